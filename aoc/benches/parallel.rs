@@ -0,0 +1,53 @@
+//! Speedup of the days with loops over independent items, see `runner::par`. Every part is
+//! measured on a rayon thread pool with a single thread and on one with a thread per core.
+//!
+//! Run with `cargo bench --features parallel --bench parallel`.
+
+use std::{hint::black_box, path::Path};
+
+use aoc::days;
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use rayon::ThreadPoolBuilder;
+use runner::Solution;
+
+fn bench_day<S: Solution<Input: Sync>>(c: &mut Criterion) {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let entry = days::find(S::DAY).unwrap();
+    let Ok((selected, input)) = entry.load_input(root, "input") else {
+        eprintln!("Day {}: no input, skipped", S::DAY);
+        return;
+    };
+    let input = S::parse_with(&runner::text::normalize(&input), &selected.params).unwrap();
+
+    let num_threads = std::thread::available_parallelism().map_or(1, usize::from);
+    let mut group = c.benchmark_group(format!("day{:02}", S::DAY));
+    group.sample_size(10);
+
+    let mut thread_counts = vec![1, num_threads];
+    thread_counts.dedup();
+    for threads in thread_counts {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::new("part1", threads), &threads, |b, _| {
+            b.iter(|| pool.install(|| black_box(S::part1(black_box(&input)))))
+        });
+        group.bench_with_input(BenchmarkId::new("part2", threads), &threads, |b, _| {
+            b.iter(|| pool.install(|| black_box(S::part2(black_box(&input)))))
+        });
+    }
+
+    group.finish();
+}
+
+fn parallel_benches(c: &mut Criterion) {
+    bench_day::<day2::Day2>(c);
+    bench_day::<day6::Day6>(c);
+    bench_day::<day7::Day7>(c);
+    bench_day::<day20::Day20>(c);
+    bench_day::<day22::Day22>(c);
+}
+
+criterion_group!(benches, parallel_benches);
+criterion_main!(benches);
@@ -0,0 +1,63 @@
+#ifndef AOC_H
+#define AOC_H
+
+#include <stddef.h>
+#include <stdint.h>
+
+/*
+ * C interface to the Rust solutions, implemented in aoc/src/ffi.rs.
+ * Build it with `cargo build --release` in aoc/ and link against libaoc.so.
+ */
+
+#define AOC_ABI_VERSION 1
+
+/* return codes of aoc_solve */
+/* solved, `answer` was filled in */
+#define AOC_OK 0
+/* `day` is not part of the library (days outside 1-25 and day 13, which needs z3) */
+#define AOC_UNKNOWN_DAY 1
+/* `input` or `answer` is NULL, or the input is not UTF-8 or not a puzzle input of `day` */
+#define AOC_INVALID_INPUT 2
+/* the solver panicked, which is a bug in the solution; the panic does not unwind into C */
+#define AOC_PANICKED 3
+
+typedef struct aoc_answer_t {
+  /*
+   * NUL terminated answers, part2 is NULL for days without a second part.
+   * They are allocated by the library and owned by the caller, who has to
+   * release them with aoc_answer_free and not with free().
+   */
+  char *part1;
+  char *part2;
+  /* time spent in each phase, in nanoseconds */
+  uint64_t parse_ns;
+  uint64_t part1_ns;
+  uint64_t part2_ns;
+} aoc_answer_t;
+
+/*
+ * Solves `day` for the `input_len` bytes at `input` (which must be UTF-8).
+ * The input stays owned by the caller and is not used after the call.
+ *
+ * Returns
+ *   AOC_OK             on success, `answer` is filled in and its strings have
+ *                      to be released with aoc_answer_free
+ *   AOC_UNKNOWN_DAY    if `day` is not part of the library
+ *   AOC_INVALID_INPUT  if `input` or `answer` is NULL, or the input is not
+ *                      UTF-8 or doesn't look like the puzzle's
+ *   AOC_PANICKED       if the solver panicked
+ * On every code but AOC_OK `answer` is left untouched and nothing has to be
+ * freed.
+ */
+int aoc_solve(uint8_t day, const char *input, size_t input_len,
+              aoc_answer_t *answer);
+
+/*
+ * Releases the strings of an answer filled in by a successful aoc_solve and
+ * sets them to NULL, so freeing the same answer twice is harmless. The
+ * aoc_answer_t itself belongs to the caller. `answer` may be NULL. Returns
+ * nothing and cannot fail.
+ */
+void aoc_answer_free(aoc_answer_t *answer);
+
+#endif
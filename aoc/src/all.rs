@@ -0,0 +1,240 @@
+use std::{
+    any::Any,
+    panic::{self, AssertUnwindSafe},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+use rayon::prelude::*;
+use runner::{cache::Cache, format_duration, Phase, PhaseReport, Report};
+
+use crate::days::{Day, DAYS, SKIPPED};
+
+/// What happened to a single day during `aoc all`.
+pub enum Outcome {
+    Solved(Report),
+    Failed {
+        day: u8,
+        reason: String,
+    },
+    /// The day is not part of the runner, see [`SKIPPED`].
+    Skipped {
+        day: u8,
+        reason: &'static str,
+    },
+}
+
+impl Outcome {
+    fn day(&self) -> u8 {
+        match self {
+            Outcome::Solved(report) => report.day,
+            Outcome::Failed { day, .. } | Outcome::Skipped { day, .. } => *day,
+        }
+    }
+}
+
+fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "panicked".to_string()
+    }
+}
+
+fn solve(entry: &Day, root: &Path, cache: &Cache) -> Outcome {
+    let (selected, input) = match entry.load_input(root, "input") {
+        Ok(loaded) => loaded,
+        Err(reason) => {
+            return Outcome::Failed {
+                day: entry.day,
+                reason,
+            }
+        }
+    };
+
+    let report = match panic::catch_unwind(AssertUnwindSafe(|| {
+        (entry.run_cached)(&input, &selected.params, cache)
+    })) {
+        Ok(Ok(report)) => report,
+        Ok(Err(err)) => {
+            return Outcome::Failed {
+                day: entry.day,
+                reason: format!("cannot parse the input: {err}"),
+            }
+        }
+        Err(payload) => {
+            return Outcome::Failed {
+                day: entry.day,
+                reason: format!("panicked: {}", panic_message(payload)),
+            }
+        }
+    };
+
+    let mismatches = report.mismatches(&selected);
+    if mismatches.is_empty() {
+        Outcome::Solved(report)
+    } else {
+        let reason = mismatches
+            .iter()
+            .map(|(phase, expected)| format!("{phase} is wrong, expected {expected}"))
+            .collect::<Vec<_>>()
+            .join(", ");
+        Outcome::Failed {
+            day: entry.day,
+            reason,
+        }
+    }
+}
+
+/// Solves every day on the rayon thread pool and returns the outcomes ordered by day. With the
+/// `parallel` feature the days use the pool themselves and are solved one after the other, see
+/// `runner::par`.
+pub fn run_all(root: &Path, cache: &Cache) -> (Vec<Outcome>, Duration) {
+    // panics are reported in the summary, the default hook would interleave them with each other
+    let hook = panic::take_hook();
+    panic::set_hook(Box::new(|_| {}));
+
+    let start = Instant::now();
+    let mut outcomes: Vec<_> = if cfg!(feature = "parallel") {
+        DAYS.iter().map(|entry| solve(entry, root, cache)).collect()
+    } else {
+        DAYS.par_iter()
+            .map(|entry| solve(entry, root, cache))
+            .collect()
+    };
+    let wall_time = start.elapsed();
+    outcomes.extend(
+        SKIPPED
+            .iter()
+            .map(|&(day, reason)| Outcome::Skipped { day, reason }),
+    );
+
+    panic::set_hook(hook);
+
+    outcomes.sort_by_key(Outcome::day);
+    (outcomes, wall_time)
+}
+
+fn format_phase(phase: PhaseReport) -> String {
+    if phase.cached {
+        "cached".to_string()
+    } else {
+        format_duration(phase.elapsed)
+    }
+}
+
+pub fn print_summary(outcomes: &[Outcome], wall_time: Duration) {
+    let header = [
+        "Day".to_string(),
+        "Task 1".to_string(),
+        "Task 2".to_string(),
+        Phase::Parse.to_string(),
+        Phase::Part1.to_string(),
+        Phase::Part2.to_string(),
+        "Total".to_string(),
+    ];
+
+    let rows: Vec<[String; 7]> = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            Outcome::Solved(report) => {
+                let [parse, part1, part2] = report.phases.map(format_phase);
+                Some([
+                    report.day.to_string(),
+                    report.part1.clone(),
+                    report.part2.clone().unwrap_or_else(|| "-".to_string()),
+                    parse,
+                    part1,
+                    part2,
+                    format_duration(report.total_time()),
+                ])
+            }
+            Outcome::Skipped { .. } | Outcome::Failed { .. } => None,
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|column| column.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let print_row = |row: &[String; 7]| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| match column {
+                // answers are left aligned, numbers and timings right aligned
+                1 | 2 => format!("{cell:<width$}"),
+                _ => format!("{cell:>width$}"),
+            })
+            .collect();
+        println!("| {} |", cells.join(" | "));
+    };
+
+    print_row(&header);
+    let separator: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    println!("|-{}-|", separator.join("-|-"));
+    for row in &rows {
+        print_row(row);
+    }
+
+    let failures: Vec<_> = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            Outcome::Failed { day, reason } => Some((day, reason)),
+            Outcome::Solved(_) | Outcome::Skipped { .. } => None,
+        })
+        .collect();
+
+    if !failures.is_empty() {
+        println!();
+        println!("Failed:");
+        for (day, reason) in &failures {
+            println!("  Day {day}: {reason}");
+        }
+    }
+
+    let skipped: Vec<_> = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            Outcome::Skipped { day, reason } => Some((day, reason)),
+            Outcome::Solved(_) | Outcome::Failed { .. } => None,
+        })
+        .collect();
+
+    if !skipped.is_empty() {
+        println!();
+        println!("Skipped:");
+        for (day, reason) in &skipped {
+            println!("  Day {day}: {reason}");
+        }
+    }
+
+    let total: Duration = outcomes
+        .iter()
+        .filter_map(|outcome| match outcome {
+            Outcome::Solved(report) => Some(report.total_time()),
+            Outcome::Failed { .. } | Outcome::Skipped { .. } => None,
+        })
+        .sum();
+    let num_solved = outcomes
+        .iter()
+        .filter(|outcome| matches!(outcome, Outcome::Solved(_)))
+        .count();
+
+    println!();
+    println!(
+        "Solved {} of {} days in {} ({} wall clock on {} threads), {} skipped",
+        num_solved,
+        outcomes.len() - SKIPPED.len(),
+        format_duration(total),
+        format_duration(wall_time),
+        rayon::current_num_threads(),
+        SKIPPED.len(),
+    );
+}
@@ -0,0 +1,308 @@
+//! Benchmark baselines and the regression check of `aoc bench --compare`.
+//!
+//! Every sample is a full run of a day, the baseline file stores the raw samples per phase. A
+//! phase counts as slower if its median got worse by more than the threshold and a one sided
+//! Mann-Whitney U test says the new samples are larger than the baseline ones, which keeps a
+//! single noisy sample from failing the check.
+
+use std::{collections::BTreeMap, io, path::Path, time::Duration};
+
+use runner::{format_duration, Params, ParseError, Phase};
+use serde::{Deserialize, Serialize};
+
+use crate::days::Day;
+
+/// z value of the one sided 95% quantile of the normal distribution.
+const Z_CRITICAL: f64 = 1.645;
+
+/// Phases in the low microseconds are dominated by timer resolution and cache effects, so
+/// smaller differences of the median are never reported.
+const MIN_CHANGE_NANOS: u64 = 5_000;
+
+/// Samples of a single day in nanoseconds, one list per phase.
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct DaySamples {
+    pub parse: Vec<u64>,
+    pub part1: Vec<u64>,
+    pub part2: Vec<u64>,
+}
+
+impl DaySamples {
+    pub fn phases(&self) -> [(Phase, &[u64]); 3] {
+        [
+            (Phase::Parse, &self.parse),
+            (Phase::Part1, &self.part1),
+            (Phase::Part2, &self.part2),
+        ]
+    }
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+pub struct Baselines {
+    #[serde(flatten)]
+    pub days: BTreeMap<String, DaySamples>,
+}
+
+fn day_key(day: u8) -> String {
+    format!("day{day:02}")
+}
+
+impl Baselines {
+    /// Loads the baselines from `path`, a missing file has no baselines.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        match std::fs::read_to_string(path) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err)),
+            Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(err),
+        }
+    }
+
+    pub fn save(&self, path: &Path) -> io::Result<()> {
+        let content = toml::to_string(self).map_err(io::Error::other)?;
+        std::fs::write(path, content)
+    }
+
+    pub fn get(&self, day: u8) -> Option<&DaySamples> {
+        self.days.get(&day_key(day))
+    }
+
+    pub fn insert(&mut self, day: u8, samples: DaySamples) {
+        self.days.insert(day_key(day), samples);
+    }
+}
+
+/// Runs `entry` once to warm up and then `num_samples` times, without the answer cache.
+pub fn sample(
+    entry: &Day,
+    input: &str,
+    params: &Params,
+    num_samples: u64,
+) -> Result<DaySamples, ParseError> {
+    (entry.run)(input, params)?;
+
+    let mut samples = DaySamples::default();
+    for _ in 0..num_samples {
+        let [parse, part1, part2] = (entry.run)(input, params)?
+            .phases
+            .map(|phase| phase.elapsed.as_nanos() as u64);
+        samples.parse.push(parse);
+        samples.part1.push(part1);
+        samples.part2.push(part2);
+    }
+    Ok(samples)
+}
+
+/// The median of `samples`, which must not be empty.
+pub fn median(samples: &[u64]) -> u64 {
+    let mut sorted = samples.to_vec();
+    sorted.sort_unstable();
+    sorted[sorted.len() / 2]
+}
+
+/// z score of the Mann-Whitney U statistic, positive if `new` tends to be larger than `base`.
+fn mann_whitney_z(base: &[u64], new: &[u64]) -> f64 {
+    let mut combined: Vec<(u64, bool)> = base
+        .iter()
+        .map(|&sample| (sample, false))
+        .chain(new.iter().map(|&sample| (sample, true)))
+        .collect();
+    combined.sort_unstable();
+
+    // ties get the average of the ranks they span and reduce the variance of U
+    let mut rank_sum_new = 0.0;
+    let mut tie_correction = 0.0;
+    let mut idx = 0;
+    while idx < combined.len() {
+        let mut end = idx;
+        while end + 1 < combined.len() && combined[end + 1].0 == combined[idx].0 {
+            end += 1;
+        }
+        let tied = (end - idx + 1) as f64;
+        tie_correction += tied * tied * tied - tied;
+        let rank = (idx + end) as f64 / 2.0 + 1.0;
+        rank_sum_new += rank
+            * combined[idx..=end]
+                .iter()
+                .filter(|(_, is_new)| *is_new)
+                .count() as f64;
+        idx = end + 1;
+    }
+
+    let n_base = base.len() as f64;
+    let n_new = new.len() as f64;
+    let u = rank_sum_new - n_new * (n_new + 1.0) / 2.0;
+    let mean = n_base * n_new / 2.0;
+    let n = n_base + n_new;
+    let variance = n_base * n_new / 12.0 * (n + 1.0 - tie_correction / (n * (n - 1.0)));
+    let std_dev = variance.max(0.0).sqrt();
+
+    // all samples are equal (or one side has none), nothing tends to be larger
+    if std_dev == 0.0 {
+        0.0
+    } else {
+        (u - mean) / std_dev
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Verdict {
+    Slower,
+    Faster,
+    Unchanged,
+}
+
+pub struct Comparison {
+    pub phase: Phase,
+    pub base_median: u64,
+    pub new_median: u64,
+    pub verdict: Verdict,
+}
+
+impl Comparison {
+    pub fn change_percent(&self) -> f64 {
+        (self.new_median as f64 / self.base_median.max(1) as f64 - 1.0) * 100.0
+    }
+}
+
+/// Compares every phase of `new` against `base`. `threshold` is the relative change of the
+/// median in percent below which a phase is considered unchanged.
+pub fn compare(base: &DaySamples, new: &DaySamples, threshold: f64) -> Vec<Comparison> {
+    base.phases()
+        .into_iter()
+        .zip(new.phases())
+        .filter(|((_, base), (_, new))| !base.is_empty() && !new.is_empty())
+        .map(|((phase, base), (_, new))| {
+            let mut comparison = Comparison {
+                phase,
+                base_median: median(base),
+                new_median: median(new),
+                verdict: Verdict::Unchanged,
+            };
+
+            let change = comparison.change_percent();
+            let z = mann_whitney_z(base, new);
+            let noticeable =
+                comparison.new_median.abs_diff(comparison.base_median) >= MIN_CHANGE_NANOS;
+            if noticeable && change > threshold && z > Z_CRITICAL {
+                comparison.verdict = Verdict::Slower;
+            } else if noticeable && change < -threshold && z < -Z_CRITICAL {
+                comparison.verdict = Verdict::Faster;
+            }
+
+            comparison
+        })
+        .collect()
+}
+
+pub fn format_nanos(nanos: u64) -> String {
+    format_duration(Duration::from_nanos(nanos))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const THRESHOLD: f64 = 5.0;
+
+    fn samples(part2: Vec<u64>) -> DaySamples {
+        DaySamples {
+            part2,
+            ..DaySamples::default()
+        }
+    }
+
+    fn verdict(base: Vec<u64>, new: Vec<u64>) -> Verdict {
+        let comparisons = compare(&samples(base), &samples(new), THRESHOLD);
+        assert_eq!(comparisons.len(), 1);
+        assert_eq!(comparisons[0].phase, Phase::Part2);
+        comparisons[0].verdict
+    }
+
+    fn around(nanos: u64) -> Vec<u64> {
+        (0..10).map(|idx| nanos + idx * 100).collect()
+    }
+
+    #[test]
+    fn shifted_samples_are_slower_or_faster() {
+        assert!(mann_whitney_z(&around(1_000_000), &around(2_000_000)) > Z_CRITICAL);
+        assert_eq!(
+            verdict(around(1_000_000), around(2_000_000)),
+            Verdict::Slower
+        );
+        assert_eq!(
+            verdict(around(2_000_000), around(1_000_000)),
+            Verdict::Faster
+        );
+    }
+
+    #[test]
+    fn identical_samples_are_unchanged() {
+        assert_eq!(mann_whitney_z(&around(1_000_000), &around(1_000_000)), 0.0);
+        assert_eq!(
+            verdict(around(1_000_000), around(1_000_000)),
+            Verdict::Unchanged
+        );
+    }
+
+    #[test]
+    fn tied_samples_are_unchanged() {
+        assert_eq!(mann_whitney_z(&[1_000_000; 10], &[1_000_000; 10]), 0.0);
+        assert_eq!(
+            verdict(vec![1_000_000; 10], vec![1_000_000; 10]),
+            Verdict::Unchanged
+        );
+    }
+
+    #[test]
+    fn small_changes_are_unchanged() {
+        // significant and 100% slower, but only by 2µs
+        assert_eq!(verdict(around(2_000), around(4_000)), Verdict::Unchanged);
+        // significant and above MIN_CHANGE_NANOS, but only 1% slower
+        assert_eq!(
+            verdict(around(1_000_000), around(1_010_000)),
+            Verdict::Unchanged
+        );
+    }
+
+    #[test]
+    fn noisy_samples_are_unchanged() {
+        // the median is 50% slower, but most samples of both runs overlap
+        let base = vec![1_000_000, 1_200_000, 1_400_000, 1_600_000, 1_800_000];
+        let new = vec![1_100_000, 1_300_000, 2_100_000, 2_200_000, 2_300_000];
+        assert_eq!(verdict(base, new), Verdict::Unchanged);
+    }
+
+    #[test]
+    fn baselines_round_trip() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baselines.toml");
+
+        let mut baselines = Baselines::default();
+        baselines.insert(
+            3,
+            DaySamples {
+                parse: vec![1, 2],
+                part1: vec![3, 4],
+                part2: vec![],
+            },
+        );
+        baselines.insert(14, samples(vec![5, 6, 7]));
+        baselines.save(&path).unwrap();
+
+        let loaded = Baselines::load(&path).unwrap();
+        assert_eq!(loaded.days.keys().collect::<Vec<_>>(), ["day03", "day14"]);
+        let day3 = loaded.get(3).unwrap();
+        assert_eq!((&day3.parse, &day3.part1), (&vec![1, 2], &vec![3, 4]));
+        assert!(day3.part2.is_empty());
+        assert_eq!(loaded.get(14).unwrap().part2, [5, 6, 7]);
+        assert!(loaded.get(1).is_none());
+    }
+
+    #[test]
+    fn missing_baselines_are_empty() {
+        let dir = tempfile::tempdir().unwrap();
+        let baselines = Baselines::load(&dir.path().join("baselines.toml")).unwrap();
+        assert!(baselines.days.is_empty());
+    }
+}
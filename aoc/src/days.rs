@@ -0,0 +1,103 @@
+use std::path::{Path, PathBuf};
+
+use runner::{
+    cache::Cache,
+    config::{DayConfig, InputConfig},
+    Params, ParseError, Phase, Report, Solution,
+};
+
+pub struct Day {
+    pub day: u8,
+    /// Only parses the input, without solving it.
+    pub parse: fn(&str, &Params) -> Result<(), ParseError>,
+    pub run: fn(&str, &Params) -> Result<Report, ParseError>,
+    pub run_cached: fn(&str, &Params, &Cache) -> Result<Report, ParseError>,
+    pub solve_part: fn(&str, &Params, Phase) -> Result<Option<String>, ParseError>,
+}
+
+fn parse_only<S: Solution>(input: &str, params: &Params) -> Result<(), ParseError> {
+    S::parse_with(&runner::text::normalize(input), params).map(drop)
+}
+
+impl Day {
+    const fn new<S: Solution>() -> Self {
+        Self {
+            day: S::DAY,
+            parse: parse_only::<S>,
+            run: runner::run::<S>,
+            run_cached: runner::cache::run_cached::<S>,
+            solve_part: runner::solve_part::<S>,
+        }
+    }
+
+    pub fn dir(&self, root: &Path) -> PathBuf {
+        day_dir(root, self.day)
+    }
+
+    /// Reads the input named `selection` in the day's `aoc.toml` (or at the path `selection`)
+    /// and returns it together with its configuration.
+    pub fn load_input(
+        &self,
+        root: &Path,
+        selection: &str,
+    ) -> Result<(InputConfig, String), String> {
+        let config = DayConfig::load(&self.dir(root))
+            .map_err(|err| format!("cannot load {}: {err}", DayConfig::FILE_NAME))?;
+        let selected = config.select(selection);
+        let input = runner::read_input(&selected.file)
+            .map_err(|err| format!("cannot read {}: {err}", selected.file.display()))?;
+        Ok((selected, input))
+    }
+}
+
+pub const DAYS: &[Day] = &[
+    Day::new::<day1::Day1>(),
+    Day::new::<day2::Day2>(),
+    Day::new::<day3::Day3>(),
+    Day::new::<day4::Day4>(),
+    Day::new::<day5::Day5>(),
+    Day::new::<day6::Day6>(),
+    Day::new::<day7::Day7>(),
+    Day::new::<day8::Day8>(),
+    Day::new::<day9::Day9>(),
+    Day::new::<day10::Day10>(),
+    Day::new::<day11::Day11>(),
+    Day::new::<day12::Day12>(),
+    Day::new::<day14::Day14>(),
+    Day::new::<day15::Day15>(),
+    Day::new::<day16::Day16>(),
+    Day::new::<day17::Day17>(),
+    Day::new::<day18::Day18>(),
+    Day::new::<day19::Day19>(),
+    Day::new::<day20::Day20>(),
+    Day::new::<day21::Day21>(),
+    Day::new::<day22::Day22>(),
+    Day::new::<day23::Day23>(),
+    Day::new::<day24::Day24>(),
+    Day::new::<day25::Day25>(),
+];
+
+/// The directory of `day`, which contains its inputs and `aoc.toml`.
+pub fn day_dir(root: &Path, day: u8) -> PathBuf {
+    root.join(format!("d{day:02}"))
+}
+
+/// The input of `day`, which might only exist encrypted as `input.txt.enc`.
+pub fn input_path(root: &Path, day: u8) -> PathBuf {
+    day_dir(root, day).join("input.txt")
+}
+
+/// Days that are not part of the runner, with the reason.
+pub const SKIPPED: &[(u8, &str)] = &[(13, "needs z3, run it from d13/rust")];
+
+/// Why `day` is not part of the runner, if it isn't.
+pub fn skip_reason(day: u8) -> Option<&'static str> {
+    SKIPPED
+        .iter()
+        .find(|&&(skipped, _)| skipped == day)
+        .map(|&(_, reason)| reason)
+}
+
+pub fn find(day: u8) -> Option<&'static Day> {
+    DAYS.iter().find(|entry| entry.day == day)
+}
@@ -0,0 +1,101 @@
+//! C ABI for the solvers, declared in `include/aoc.h`.
+//!
+//! The layout of [`AocAnswer`] and the status codes are part of that header, so they must only
+//! ever be extended together with `AOC_ABI_VERSION`.
+
+use std::{
+    ffi::{c_char, CString},
+    panic::{self, AssertUnwindSafe},
+    ptr,
+};
+
+use runner::Params;
+
+use crate::days;
+
+pub const AOC_OK: i32 = 0;
+pub const AOC_UNKNOWN_DAY: i32 = 1;
+pub const AOC_INVALID_INPUT: i32 = 2;
+pub const AOC_PANICKED: i32 = 3;
+
+#[repr(C)]
+pub struct AocAnswer {
+    /// Answer of part 1 as a NUL terminated string.
+    pub part1: *mut c_char,
+    /// Answer of part 2 as a NUL terminated string, null for days without a second part.
+    pub part2: *mut c_char,
+    pub parse_ns: u64,
+    pub part1_ns: u64,
+    pub part2_ns: u64,
+}
+
+fn into_c_string(answer: String) -> *mut c_char {
+    // the answers are numbers or comma separated lists, they never contain a NUL byte
+    CString::new(answer).unwrap().into_raw()
+}
+
+/// Solves `day` for the input at `input`/`input_len` and stores the answers in `answer`.
+///
+/// # Safety
+///
+/// `input` must point to `input_len` readable bytes and `answer` must be valid for writes. On
+/// success the strings in `answer` are owned by the caller and have to be released with
+/// [`aoc_answer_free`].
+#[no_mangle]
+pub unsafe extern "C" fn aoc_solve(
+    day: u8,
+    input: *const u8,
+    input_len: usize,
+    answer: *mut AocAnswer,
+) -> i32 {
+    if input.is_null() || answer.is_null() {
+        return AOC_INVALID_INPUT;
+    }
+
+    let Some(entry) = days::find(day) else {
+        return AOC_UNKNOWN_DAY;
+    };
+
+    let bytes = std::slice::from_raw_parts(input, input_len);
+    let Ok(input) = std::str::from_utf8(bytes) else {
+        return AOC_INVALID_INPUT;
+    };
+
+    // unwinding into C is undefined behaviour
+    let report =
+        match panic::catch_unwind(AssertUnwindSafe(|| (entry.run)(input, &Params::default()))) {
+            Ok(Ok(report)) => report,
+            Ok(Err(_)) => return AOC_INVALID_INPUT,
+            Err(_) => return AOC_PANICKED,
+        };
+
+    let [parse, part1, part2] = report.phases.map(|phase| phase.elapsed.as_nanos() as u64);
+    answer.write(AocAnswer {
+        part1: into_c_string(report.part1),
+        part2: report.part2.map_or(ptr::null_mut(), into_c_string),
+        parse_ns: parse,
+        part1_ns: part1,
+        part2_ns: part2,
+    });
+
+    AOC_OK
+}
+
+/// Releases the strings of an answer filled in by [`aoc_solve`] and resets them to null.
+///
+/// # Safety
+///
+/// `answer` must be null or point to an answer written by [`aoc_solve`].
+#[no_mangle]
+pub unsafe extern "C" fn aoc_answer_free(answer: *mut AocAnswer) {
+    let Some(answer) = answer.as_mut() else {
+        return;
+    };
+
+    for part in [&mut answer.part1, &mut answer.part2] {
+        if !part.is_null() {
+            drop(CString::from_raw(*part));
+            *part = ptr::null_mut();
+        }
+    }
+}
@@ -0,0 +1,5 @@
+pub mod all;
+pub mod bench;
+pub mod days;
+pub mod ffi;
+pub mod watch;
@@ -0,0 +1,313 @@
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use aoc::{
+    all,
+    bench::{self, Baselines, Verdict},
+    days, watch,
+};
+use clap::{Parser, Subcommand};
+use runner::cache::Cache;
+
+#[derive(Parser)]
+#[command(about = "Runs the Advent of Code 2024 solutions")]
+struct Cli {
+    /// Ignore cached answers and solve everything again.
+    #[arg(long, global = true)]
+    fresh: bool,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve a single day and report timings and heap usage per phase.
+    Run {
+        day: u8,
+        /// Name of an input in the day's aoc.toml, a path, or `-` to read it from stdin.
+        #[arg(default_value = "input")]
+        input: String,
+    },
+    /// Solve every day (concurrently without the `parallel` feature) and print a summary table.
+    All,
+    /// Time the given days (default: all) and optionally compare them against a stored baseline.
+    Bench {
+        days: Vec<u8>,
+        /// Number of timed runs per day, after one warm up run.
+        #[arg(long, default_value_t = 10, value_parser = clap::value_parser!(u64).range(1..))]
+        samples: u64,
+        /// Store the timings as the new baseline of the benchmarked days.
+        #[arg(long)]
+        save: bool,
+        /// Compare against the baseline and fail on significant slowdowns.
+        #[arg(long)]
+        compare: bool,
+        /// Change of the median in percent that is still considered noise.
+        #[arg(long, default_value_t = 5.0)]
+        threshold: f64,
+        /// Baseline file, defaults to aoc/bench-baseline.toml.
+        #[arg(long)]
+        baseline: Option<PathBuf>,
+    },
+    /// Build and solve all inputs of a day again whenever its sources or inputs change.
+    Watch {
+        #[arg(long)]
+        day: u8,
+    },
+    /// Write an encrypted input.txt.enc next to the input of the given days (default: all).
+    Encrypt { days: Vec<u8> },
+    /// Restore input.txt from input.txt.enc for the given days (default: all).
+    Decrypt { days: Vec<u8> },
+}
+
+/// The repository root, which contains one `dXX` directory per day.
+fn repo_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .unwrap()
+        .to_path_buf()
+}
+
+/// Answers are cached per build, so the cache lives next to the build output.
+fn open_cache(fresh: bool) -> Cache {
+    let dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("target")
+        .join("answer-cache");
+    Cache::new(dir, fresh).unwrap()
+}
+
+fn selected_days(days: Vec<u8>) -> Vec<u8> {
+    if days.is_empty() {
+        (1..=25).collect()
+    } else {
+        days
+    }
+}
+
+fn encrypt(days: Vec<u8>) -> io::Result<()> {
+    let root = repo_root();
+    for day in selected_days(days) {
+        let path = days::input_path(&root, day);
+        if !path.exists() {
+            continue;
+        }
+
+        let plain = std::fs::read(&path)?;
+        let encrypted_path = runner::crypt::encrypted_path(&path);
+        // salt and nonce are random, so an unchanged input keeps its file to not show up in git
+        let unchanged = std::fs::read(&encrypted_path)
+            .and_then(|encrypted| runner::crypt::decrypt(&encrypted))
+            .is_ok_and(|existing| existing.as_bytes() == plain);
+        if unchanged {
+            continue;
+        }
+
+        std::fs::write(encrypted_path, runner::crypt::encrypt(&plain)?)?;
+        println!("Encrypted {}", path.display());
+    }
+    Ok(())
+}
+
+fn decrypt(days: Vec<u8>) -> io::Result<()> {
+    let root = repo_root();
+    for day in selected_days(days) {
+        let path = days::input_path(&root, day);
+        let encrypted_path = runner::crypt::encrypted_path(&path);
+        if !encrypted_path.exists() {
+            continue;
+        }
+
+        let input = runner::crypt::decrypt(&std::fs::read(&encrypted_path)?)?;
+        std::fs::write(&path, input)?;
+        println!("Decrypted {}", encrypted_path.display());
+    }
+    Ok(())
+}
+
+struct BenchOptions {
+    samples: u64,
+    save: bool,
+    compare: bool,
+    threshold: f64,
+    baseline: Option<PathBuf>,
+}
+
+/// Returns whether a significant slowdown was found.
+fn bench(days: Vec<u8>, options: BenchOptions) -> io::Result<bool> {
+    let root = repo_root();
+    let baseline_path = options
+        .baseline
+        .unwrap_or_else(|| Path::new(env!("CARGO_MANIFEST_DIR")).join("bench-baseline.toml"));
+    let mut baselines = Baselines::load(&baseline_path)?;
+    let mut regressed = false;
+
+    for day in selected_days(days) {
+        let Some(entry) = days::find(day) else {
+            if let Some(reason) = days::skip_reason(day) {
+                println!("Day {day}: skipped, {reason}");
+            }
+            continue;
+        };
+        let Ok((selected, input)) = entry.load_input(&root, "input") else {
+            println!("Day {day}: no input, skipped");
+            continue;
+        };
+
+        let samples = match bench::sample(entry, &input, &selected.params, options.samples) {
+            Ok(samples) => samples,
+            Err(err) => {
+                println!("Day {day}: cannot parse the input, skipped: {err}");
+                continue;
+            }
+        };
+
+        println!("Day {day}");
+        if options.compare {
+            let Some(base) = baselines.get(day) else {
+                println!("  no baseline");
+                continue;
+            };
+
+            for comparison in bench::compare(base, &samples, options.threshold) {
+                let verdict = match comparison.verdict {
+                    Verdict::Slower => {
+                        regressed = true;
+                        "SLOWER"
+                    }
+                    Verdict::Faster => "faster",
+                    Verdict::Unchanged => "",
+                };
+                let line = format!(
+                    "  {:<7} {:>8} -> {:>8} ({:+6.1}%) {verdict}",
+                    comparison.phase.to_string(),
+                    bench::format_nanos(comparison.base_median),
+                    bench::format_nanos(comparison.new_median),
+                    comparison.change_percent(),
+                );
+                println!("{}", line.trim_end());
+            }
+        } else {
+            for (phase, phase_samples) in samples.phases() {
+                println!(
+                    "  {:<7} {:>8} (median of {})",
+                    phase.to_string(),
+                    bench::format_nanos(bench::median(phase_samples)),
+                    phase_samples.len()
+                );
+            }
+        }
+
+        if options.save {
+            baselines.insert(day, samples);
+        }
+    }
+
+    if options.save {
+        baselines.save(&baseline_path)?;
+        println!("Saved baseline to {}", baseline_path.display());
+    }
+
+    Ok(regressed)
+}
+
+fn main() {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, input } => {
+            let Some(entry) = days::find(day) else {
+                match days::skip_reason(day) {
+                    Some(reason) => eprintln!("Day {day} is skipped: {reason}"),
+                    None => eprintln!("Day {day} is not part of the runner"),
+                }
+                std::process::exit(1);
+            };
+
+            let (selected, input) = match entry.load_input(&repo_root(), &input) {
+                Ok(loaded) => loaded,
+                Err(err) => {
+                    eprintln!("Day {day}: {err}");
+                    std::process::exit(1);
+                }
+            };
+            let report = match (entry.run_cached)(&input, &selected.params, &open_cache(cli.fresh))
+            {
+                Ok(report) => report,
+                Err(err) => {
+                    eprintln!("Day {day}: cannot parse the input: {err}");
+                    std::process::exit(1);
+                }
+            };
+
+            println!("Day {}", report.day);
+            print!("{report}");
+
+            let mismatches = report.mismatches(&selected);
+            for (phase, expected) in &mismatches {
+                eprintln!("{phase} is wrong, expected {expected}");
+            }
+            if !mismatches.is_empty() {
+                std::process::exit(1);
+            }
+        }
+        Command::All => {
+            let (outcomes, wall_time) = all::run_all(&repo_root(), &open_cache(cli.fresh));
+            all::print_summary(&outcomes, wall_time);
+
+            if outcomes
+                .iter()
+                .any(|outcome| matches!(outcome, all::Outcome::Failed { .. }))
+            {
+                std::process::exit(1);
+            }
+        }
+        Command::Bench {
+            days,
+            samples,
+            save,
+            compare,
+            threshold,
+            baseline,
+        } => {
+            let options = BenchOptions {
+                samples,
+                save,
+                compare,
+                threshold,
+                baseline,
+            };
+            match bench(days, options) {
+                Ok(false) => {}
+                Ok(true) => {
+                    eprintln!("Significant slowdown against the baseline");
+                    std::process::exit(1);
+                }
+                Err(err) => {
+                    eprintln!("Benchmark failed: {err}");
+                    std::process::exit(1);
+                }
+            }
+        }
+        Command::Watch { day } => {
+            if let Err(err) = watch::watch(&repo_root(), day) {
+                eprintln!("Watching day {day} failed: {err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Encrypt { days } => {
+            if let Err(err) = encrypt(days) {
+                eprintln!("Encryption failed: {err}");
+                std::process::exit(1);
+            }
+        }
+        Command::Decrypt { days } => {
+            if let Err(err) = decrypt(days) {
+                eprintln!("Decryption failed: {err}");
+                std::process::exit(1);
+            }
+        }
+    }
+}
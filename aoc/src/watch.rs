@@ -0,0 +1,126 @@
+//! `aoc watch`: builds a day and solves all of its inputs again whenever its sources, its
+//! `aoc.toml` or one of its inputs change.
+//!
+//! The day is built and run through its own crate with cargo, so changes to the solver are picked
+//! up without rebuilding the runner, and day 13 can be watched as well.
+
+use std::{
+    ffi::OsStr,
+    io,
+    path::{Path, PathBuf},
+    process::Command,
+    sync::mpsc,
+    time::Duration,
+};
+
+use notify::{Event, EventKind, RecursiveMode, Watcher};
+use runner::config::DayConfig;
+
+use crate::days;
+
+/// Editors save a file in several steps, changes within this time are handled together.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// Files next to the sources that cargo writes itself, reacting to them would rebuild forever.
+const IGNORED: [&str; 2] = ["target", "Cargo.lock"];
+
+/// The directory with the day's `Cargo.toml`, days 9 to 14 keep their crate in `rust/`.
+pub fn crate_dir(day_dir: &Path) -> PathBuf {
+    let nested = day_dir.join("rust");
+    if nested.join("Cargo.toml").exists() {
+        nested
+    } else {
+        day_dir.to_path_buf()
+    }
+}
+
+fn is_relevant(event: &Event) -> bool {
+    let changed = matches!(
+        event.kind,
+        EventKind::Create(_) | EventKind::Modify(_) | EventKind::Remove(_)
+    );
+    changed
+        && event.paths.iter().any(|path| {
+            path.file_name()
+                .is_some_and(|name| !IGNORED.iter().any(|ignored| name == OsStr::new(ignored)))
+        })
+}
+
+fn cargo(crate_dir: &Path, args: &[&str]) -> io::Result<bool> {
+    let status = Command::new("cargo")
+        .args(args)
+        .current_dir(crate_dir)
+        .status()?;
+    Ok(status.success())
+}
+
+/// Builds the day and solves every input of its `aoc.toml`, the binary of the day prints the
+/// answers and the ones that differ from the expected answers.
+fn run_inputs(day: u8, day_dir: &Path) {
+    let crate_dir = crate_dir(day_dir);
+    println!("=== Day {day} ===");
+
+    match cargo(&crate_dir, &["build", "--release", "--quiet"]) {
+        Ok(true) => {}
+        Ok(false) => {
+            println!("Build failed, waiting for changes");
+            return;
+        }
+        Err(err) => {
+            println!("Cannot run cargo: {err}");
+            return;
+        }
+    }
+
+    let config = match DayConfig::load(day_dir) {
+        Ok(config) => config,
+        Err(err) => {
+            println!("Cannot load {}: {err}", DayConfig::FILE_NAME);
+            return;
+        }
+    };
+
+    let mut failed = Vec::new();
+    for input in &config.inputs {
+        println!("--- {} ---", input.name);
+        let args = ["run", "--release", "--quiet", "--", &input.name];
+        if !cargo(&crate_dir, &args).unwrap_or(false) {
+            failed.push(input.name.as_str());
+        }
+    }
+
+    if failed.is_empty() {
+        println!("All {} inputs are correct", config.inputs.len());
+    } else {
+        println!("Wrong or failed: {}", failed.join(", "));
+    }
+}
+
+/// Runs all inputs of `day` once and again after every change, until the watcher fails.
+pub fn watch(root: &Path, day: u8) -> notify::Result<()> {
+    let day_dir = days::day_dir(root, day);
+    let crate_dir = crate_dir(&day_dir);
+
+    let (sender, receiver) = mpsc::channel();
+    let mut watcher = notify::recommended_watcher(sender)?;
+    // the inputs and aoc.toml, without descending into target/
+    watcher.watch(&day_dir, RecursiveMode::NonRecursive)?;
+    if crate_dir != day_dir {
+        watcher.watch(&crate_dir, RecursiveMode::NonRecursive)?;
+    }
+    watcher.watch(&crate_dir.join("src"), RecursiveMode::Recursive)?;
+
+    run_inputs(day, &day_dir);
+    println!("Watching {} for changes", day_dir.display());
+
+    for event in &receiver {
+        if !is_relevant(&event?) {
+            continue;
+        }
+        while receiver.recv_timeout(DEBOUNCE).is_ok() {}
+
+        run_inputs(day, &day_dir);
+    }
+
+    Ok(())
+}
@@ -0,0 +1,91 @@
+use std::path::Path;
+
+use aoc::days::{self, DAYS};
+use runner::{config::DayConfig, Phase};
+
+/// Checks the answers of every example against the expected ones from the `aoc.toml` files. The
+/// real inputs are left to `aoc all`, which runs them in release mode.
+#[test]
+fn examples_match_expected_answers() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+    let mut failures = Vec::new();
+
+    for entry in DAYS {
+        let config = DayConfig::load(&entry.dir(root)).unwrap();
+
+        for example in config.inputs.iter().filter(|input| input.name != "input") {
+            let input = runner::read_input(&example.file).unwrap();
+
+            for (phase, expected) in [
+                (Phase::Part1, &example.part1),
+                (Phase::Part2, &example.part2),
+            ] {
+                let Some(expected) = expected else {
+                    continue;
+                };
+
+                let answer =
+                    (entry.solve_part)(&input, &example.params, phase).unwrap_or_else(|err| {
+                        panic!("day {} {}: cannot parse: {err}", entry.day, example.name)
+                    });
+                if answer.as_ref() != Some(expected) {
+                    failures.push(format!(
+                        "day {} {} {phase}: expected {expected}, got {answer:?}",
+                        entry.day, example.name
+                    ));
+                }
+            }
+        }
+    }
+
+    assert!(failures.is_empty(), "{}", failures.join("\n"));
+}
+
+/// Solves both parts of every example like `aoc run` does, also the parts the `aoc.toml` files
+/// list no answer for, because the example has none.
+#[test]
+fn examples_solve_every_part() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    for entry in DAYS {
+        let config = DayConfig::load(&entry.dir(root)).unwrap();
+
+        for example in config.inputs.iter().filter(|input| input.name != "input") {
+            let input = runner::read_input(&example.file).unwrap();
+            (entry.run)(&input, &example.params).unwrap_or_else(|err| {
+                panic!("day {} {}: cannot parse: {err}", entry.day, example.name)
+            });
+        }
+    }
+}
+
+/// The days whose parsers split the input into sections or failed on a trailing newline before
+/// `runner::text`. Day 13 has the same check in its own crate.
+const SECTIONED_DAYS: [u8; 6] = [9, 15, 17, 19, 24, 25];
+
+/// `input` like an editor on Windows saves it, with trailing spaces and a trailing blank line.
+fn with_crlf_and_trailing_spaces(input: &str) -> String {
+    let mut messy: String = input.lines().map(|line| format!("{line}  \r\n")).collect();
+    messy.push_str("\r\n");
+    messy
+}
+
+#[test]
+fn sectioned_examples_accept_crlf_and_trailing_spaces() {
+    let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+
+    for day in SECTIONED_DAYS {
+        let entry = days::find(day).unwrap();
+        let config = DayConfig::load(&entry.dir(root)).unwrap();
+        let example = config
+            .inputs
+            .iter()
+            .find(|input| input.name != "input" && input.part1.is_some())
+            .unwrap();
+        let input = with_crlf_and_trailing_spaces(&runner::read_input(&example.file).unwrap());
+
+        let answer = (entry.solve_part)(&input, &example.params, Phase::Part1)
+            .unwrap_or_else(|err| panic!("day {day} {}: cannot parse: {err}", example.name));
+        assert_eq!(answer, example.part1, "day {day} {}", example.name);
+    }
+}
@@ -0,0 +1,168 @@
+//! Every parser has to turn any input into either a model or an error, without panicking or
+//! hanging. The inputs are arbitrary strings and bytes as well as the examples with a few
+//! characters or lines changed, which get further into the parsers. Day 13 has its own copy in
+//! `d13/rust/tests/parser.rs`, because it can't be part of `DAYS`.
+
+use std::{
+    path::Path,
+    sync::{
+        mpsc::{self, RecvTimeoutError},
+        OnceLock,
+    },
+    thread,
+    time::Duration,
+};
+
+use aoc::days::{Day, DAYS};
+use proptest::{prelude::*, sample::Index};
+use runner::{config::DayConfig, Params};
+
+/// Parsing one of the inputs below takes microseconds, anything this slow hangs.
+const TIMEOUT: Duration = Duration::from_secs(5);
+
+/// Parses `input` on its own thread, so a panic or a hang fails the case instead of the test
+/// binary. Errors are fine, they are what the parsers should return for broken inputs.
+fn check_parser(entry: &'static Day, params: &Params, input: &str) -> Result<(), TestCaseError> {
+    let (sender, receiver) = mpsc::channel();
+    let params = params.clone();
+    let owned_input = input.to_string();
+    thread::spawn(move || {
+        let _ = sender.send((entry.parse)(&owned_input, &params));
+    });
+
+    match receiver.recv_timeout(TIMEOUT) {
+        Ok(_) => Ok(()),
+        Err(RecvTimeoutError::Timeout) => Err(TestCaseError::fail(format!(
+            "day {} hangs on {input:?}",
+            entry.day
+        ))),
+        Err(RecvTimeoutError::Disconnected) => Err(TestCaseError::fail(format!(
+            "day {} panics on {input:?}",
+            entry.day
+        ))),
+    }
+}
+
+fn check_all_parsers(input: &str) -> Result<(), TestCaseError> {
+    for entry in DAYS {
+        check_parser(entry, &Params::default(), input)?;
+    }
+    Ok(())
+}
+
+/// An example of a day, the starting point of the mutations.
+struct Example {
+    entry: &'static Day,
+    params: Params,
+    input: String,
+}
+
+fn examples() -> &'static [Example] {
+    static EXAMPLES: OnceLock<Vec<Example>> = OnceLock::new();
+    EXAMPLES.get_or_init(|| {
+        let root = Path::new(env!("CARGO_MANIFEST_DIR")).parent().unwrap();
+        DAYS.iter()
+            .flat_map(|entry| {
+                let config = DayConfig::load(&entry.dir(root)).unwrap();
+                config
+                    .inputs
+                    .into_iter()
+                    .filter(|input| input.name != "input")
+                    .map(move |example| Example {
+                        entry,
+                        input: runner::read_input(&example.file).unwrap(),
+                        params: example.params,
+                    })
+            })
+            .collect()
+    })
+}
+
+#[derive(Clone, Debug)]
+enum Mutation {
+    Insert(Index, char),
+    Delete(Index),
+    Replace(Index, char),
+    Truncate(Index),
+    DuplicateLine(Index),
+    DeleteLine(Index),
+}
+
+impl Mutation {
+    fn apply(&self, input: &str) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        let mut lines: Vec<&str> = input.split_inclusive('\n').collect();
+        match *self {
+            Mutation::Insert(idx, c) => chars.insert(idx.index(chars.len() + 1), c),
+            Mutation::Delete(idx) if !chars.is_empty() => {
+                chars.remove(idx.index(chars.len()));
+            }
+            Mutation::Replace(idx, c) if !chars.is_empty() => {
+                let idx = idx.index(chars.len());
+                chars[idx] = c;
+            }
+            Mutation::Truncate(idx) => chars.truncate(idx.index(chars.len() + 1)),
+            Mutation::DuplicateLine(idx) if !lines.is_empty() => {
+                let idx = idx.index(lines.len());
+                lines.insert(idx, lines[idx]);
+                return lines.concat();
+            }
+            Mutation::DeleteLine(idx) if !lines.is_empty() => {
+                lines.remove(idx.index(lines.len()));
+                return lines.concat();
+            }
+            _ => {}
+        }
+        chars.into_iter().collect()
+    }
+}
+
+/// Mostly characters that already appear in the inputs, so the mutations stay close to them.
+fn puzzle_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        3 => proptest::char::range(' ', '~'),
+        1 => Just('\n'),
+        1 => any::<char>(),
+    ]
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        (any::<Index>(), puzzle_char()).prop_map(|(idx, c)| Mutation::Insert(idx, c)),
+        any::<Index>().prop_map(Mutation::Delete),
+        (any::<Index>(), puzzle_char()).prop_map(|(idx, c)| Mutation::Replace(idx, c)),
+        any::<Index>().prop_map(Mutation::Truncate),
+        any::<Index>().prop_map(Mutation::DuplicateLine),
+        any::<Index>().prop_map(Mutation::DeleteLine),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn parsers_accept_arbitrary_strings(input in any::<String>()) {
+        check_all_parsers(&input)?;
+    }
+
+    #[test]
+    fn parsers_accept_printable_lines(input in "[ -~\n]{0,200}") {
+        check_all_parsers(&input)?;
+    }
+
+    #[test]
+    fn parsers_accept_arbitrary_bytes(bytes in proptest::collection::vec(any::<u8>(), 0..256)) {
+        check_all_parsers(&String::from_utf8_lossy(&bytes))?;
+    }
+
+    #[test]
+    fn parsers_accept_mutated_examples(
+        example in any::<Index>(),
+        mutations in proptest::collection::vec(mutation(), 1..8),
+    ) {
+        let example = example.get(examples());
+        let input = mutations
+            .iter()
+            .fold(example.input.clone(), |input, mutation| mutation.apply(&input));
+        check_parser(example.entry, &example.params, &input)?;
+        check_parser(example.entry, &Params::default(), &input)?;
+    }
+}
@@ -0,0 +1,92 @@
+//! The radix sort, byte parser and merge of day 1 against the straightforward solution, on
+//! generated lists up to a million lines.
+
+use std::{fmt::Write, hint::black_box};
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day1::{
+    parse, parse_bytes, similarity_score, solve_a, solve_b, sort_locations, sorted, total_distance,
+};
+use proptest::{
+    strategy::{Strategy, ValueTree},
+    test_runner::TestRunner,
+};
+
+/// Five digit IDs like the real input, from proptest's deterministic runner so every run
+/// benchmarks the same lists.
+fn generate_input(num_lines: usize) -> String {
+    let id = 10_000..100_000_u64;
+    let pairs = proptest::collection::vec((id.clone(), id), num_lines)
+        .new_tree(&mut TestRunner::deterministic())
+        .unwrap()
+        .current();
+
+    let mut input = String::new();
+    for (a, b) in pairs {
+        writeln!(input, "{a}   {b}").unwrap();
+    }
+    input
+}
+
+fn day1_benches(c: &mut Criterion) {
+    for num_lines in [1_000, 100_000, 1_000_000] {
+        let input = generate_input(num_lines);
+        let lists = parse_bytes(input.as_bytes()).unwrap();
+        let (a_sorted, b_sorted) = sorted(lists.clone());
+
+        let mut group = c.benchmark_group("day01");
+        group.sample_size(10);
+
+        group.bench_with_input(
+            BenchmarkId::new("parse/lines", num_lines),
+            &input,
+            |b, input| b.iter(|| parse(black_box(input))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("parse/bytes", num_lines),
+            &input,
+            |b, input| b.iter(|| parse_bytes(black_box(input.as_bytes()))),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("sort/std", num_lines),
+            &lists.0,
+            |b, list| b.iter(|| black_box(list.clone()).sort_unstable()),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("sort/radix", num_lines),
+            &lists.0,
+            |b, list| b.iter(|| sort_locations(&mut black_box(list.clone()))),
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("part1/std", num_lines),
+            &lists,
+            |b, (a, b_list)| b.iter(|| solve_a(black_box(a.clone()), black_box(b_list.clone()))),
+        );
+        group.bench_with_input(
+            BenchmarkId::new("part1/radix", num_lines),
+            &lists,
+            |b, lists| {
+                b.iter(|| {
+                    let (a_sorted, b_sorted) = sorted(black_box(lists.clone()));
+                    total_distance(&a_sorted, &b_sorted)
+                })
+            },
+        );
+
+        group.bench_with_input(
+            BenchmarkId::new("part2/hash", num_lines),
+            &lists,
+            |b, (a, b_list)| b.iter(|| solve_b(black_box(a.clone()), black_box(b_list.clone()))),
+        );
+        group.bench_function(BenchmarkId::new("part2/merge", num_lines), |b| {
+            b.iter(|| similarity_score(black_box(&a_sorted), black_box(&b_sorted)))
+        });
+
+        group.finish();
+    }
+}
+
+criterion_group!(benches, day1_benches);
+criterion_main!(benches);
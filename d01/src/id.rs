@@ -0,0 +1,92 @@
+//! The integer types the location IDs can be parsed as, for generated inputs with negative IDs or
+//! IDs beyond `u64`.
+//!
+//! The distances and similarities are summed in a wider type than the IDs, so the answers are
+//! exact for lists of up to 2^32 lines with the fixed width types and for any list with big
+//! integers.
+
+use std::{
+    fmt::Display,
+    ops::{AddAssign, SubAssign},
+    str::FromStr,
+};
+
+use num_bigint::BigInt;
+use runner::ParseError;
+
+use crate::sort::sort_locations;
+
+pub trait LocationId: Ord + Clone + FromStr {
+    /// The type the answers are summed up in.
+    type Total: Default + AddAssign + SubAssign + Display;
+
+    /// The absolute difference between two IDs.
+    fn distance(&self, other: &Self) -> Self::Total;
+
+    /// The ID times the number of pairs it appears in, `count`.
+    fn similarity(&self, count: u64) -> Self::Total;
+
+    fn sort(list: &mut [Self]) {
+        list.sort_unstable();
+    }
+
+    fn parse_lists(input: &str) -> Result<(Vec<Self>, Vec<Self>), ParseError> {
+        crate::parse_as(input)
+    }
+}
+
+impl LocationId for u64 {
+    type Total = u128;
+
+    fn distance(&self, other: &Self) -> u128 {
+        self.abs_diff(*other) as u128
+    }
+
+    fn similarity(&self, count: u64) -> u128 {
+        *self as u128 * count as u128
+    }
+
+    fn sort(list: &mut [Self]) {
+        sort_locations(list);
+    }
+
+    fn parse_lists(input: &str) -> Result<(Vec<Self>, Vec<Self>), ParseError> {
+        crate::parse_bytes(input.as_bytes())
+    }
+}
+
+impl LocationId for i64 {
+    type Total = i128;
+
+    fn distance(&self, other: &Self) -> i128 {
+        self.abs_diff(*other) as i128
+    }
+
+    fn similarity(&self, count: u64) -> i128 {
+        *self as i128 * count as i128
+    }
+}
+
+impl LocationId for u128 {
+    type Total = BigInt;
+
+    fn distance(&self, other: &Self) -> BigInt {
+        BigInt::from(self.abs_diff(*other))
+    }
+
+    fn similarity(&self, count: u64) -> BigInt {
+        BigInt::from(*self) * count
+    }
+}
+
+impl LocationId for BigInt {
+    type Total = BigInt;
+
+    fn distance(&self, other: &Self) -> BigInt {
+        BigInt::from((self - other).magnitude().clone())
+    }
+
+    fn similarity(&self, count: u64) -> BigInt {
+        self * count
+    }
+}
@@ -0,0 +1,234 @@
+use std::{
+    collections::HashMap,
+    io::{self, Read},
+    iter::zip,
+    marker::PhantomData,
+    str::FromStr,
+};
+
+use runner::{overflow, parse, ParseError, Solution};
+
+mod id;
+mod online;
+mod ost;
+mod sort;
+
+pub use id::LocationId;
+pub use online::{Edit, OnlineLists};
+pub use ost::OrderStatisticTree;
+pub use sort::sort_locations;
+
+/// The straightforward part 1 with comparison sorts, the baseline of the benchmarks.
+pub fn solve_a(mut a_list: Vec<u64>, mut b_list: Vec<u64>) -> u64 {
+    a_list.sort();
+    b_list.sort();
+    zip(a_list.iter(), b_list.iter())
+        .map(|(&a, &b)| a.abs_diff(b))
+        .sum()
+}
+
+/// The straightforward part 2 with a hash map of the counts, the baseline of the benchmarks.
+pub fn solve_b(a_list: Vec<u64>, b_list: Vec<u64>) -> u64 {
+    let mut b_counts = HashMap::new();
+    for b in b_list {
+        *b_counts.entry(b).or_insert(0) += 1;
+    }
+
+    let mut sum = 0;
+    for a in a_list {
+        if b_counts.contains_key(&a) {
+            sum += *b_counts.get(&a).unwrap() * a;
+        }
+    }
+
+    sum
+}
+
+/// Sum of the distances between the lists, which both have to be sorted.
+pub fn total_distance<T: LocationId>(a_sorted: &[T], b_sorted: &[T]) -> T::Total {
+    let mut sum = T::Total::default();
+    for (a, b) in zip(a_sorted, b_sorted) {
+        sum += a.distance(b);
+    }
+    sum
+}
+
+/// The similarity score of two sorted lists, computed by merging them: every ID contributes
+/// itself times its count in both lists.
+pub fn similarity_score<T: LocationId>(a_sorted: &[T], b_sorted: &[T]) -> T::Total {
+    let mut score = T::Total::default();
+    let (mut a_idx, mut b_idx) = (0, 0);
+
+    while a_idx < a_sorted.len() && b_idx < b_sorted.len() {
+        let (a, b) = (&a_sorted[a_idx], &b_sorted[b_idx]);
+        if a < b {
+            a_idx += 1;
+        } else if b < a {
+            b_idx += 1;
+        } else {
+            let a_count = a_sorted[a_idx..].iter().take_while(|&id| id == a).count();
+            let b_count = b_sorted[b_idx..].iter().take_while(|&id| id == b).count();
+            a_idx += a_count;
+            b_idx += b_count;
+
+            let count = overflow::mul(1, "a count * b count", a_count as u64, b_count as u64);
+            score += a.similarity(count);
+        }
+    }
+
+    score
+}
+
+/// Parses the lists from chunks of bytes, like the blocks of a file that doesn't fit into memory
+/// as a string. Numbers and lines may be split between chunks.
+#[derive(Default)]
+pub struct ListParser {
+    a_list: Vec<u64>,
+    b_list: Vec<u64>,
+    line_idx: usize,
+    line_started: bool,
+    numbers: [u64; 2],
+    num_numbers: usize,
+    in_number: bool,
+}
+
+impl ListParser {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn feed(&mut self, chunk: &[u8]) -> Result<(), ParseError> {
+        for &byte in chunk {
+            match byte {
+                b'0'..=b'9' => self.push_digit(byte - b'0')?,
+                b' ' | b'\t' | b'\r' => {
+                    self.line_started = true;
+                    self.in_number = false;
+                }
+                b'\n' => self.end_line()?,
+                _ if byte.is_ascii() => {
+                    return Err(ParseError::unexpected_char(byte as char).in_line(self.line_idx))
+                }
+                _ => {
+                    let message = format!("unexpected byte {byte:#04x}");
+                    return Err(ParseError::new(message).in_line(self.line_idx));
+                }
+            }
+        }
+        Ok(())
+    }
+
+    /// Ends the last line, which doesn't need a line break, and returns the lists in input order.
+    pub fn finish(mut self) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+        if self.line_started {
+            self.end_line()?;
+        }
+        Ok((self.a_list, self.b_list))
+    }
+
+    fn push_digit(&mut self, digit: u8) -> Result<(), ParseError> {
+        self.line_started = true;
+        if !self.in_number {
+            if self.num_numbers == 2 {
+                return Err(ParseError::new("expected two numbers").in_line(self.line_idx));
+            }
+            self.in_number = true;
+            self.numbers[self.num_numbers] = 0;
+            self.num_numbers += 1;
+        }
+
+        let number = &mut self.numbers[self.num_numbers - 1];
+        *number = number
+            .checked_mul(10)
+            .and_then(|number| number.checked_add(digit as u64))
+            .ok_or_else(|| ParseError::new("number too large").in_line(self.line_idx))?;
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), ParseError> {
+        if self.num_numbers != 2 {
+            return Err(ParseError::new("expected two numbers").in_line(self.line_idx));
+        }
+        self.a_list.push(self.numbers[0]);
+        self.b_list.push(self.numbers[1]);
+
+        self.line_idx += 1;
+        self.line_started = false;
+        self.num_numbers = 0;
+        self.in_number = false;
+        Ok(())
+    }
+}
+
+/// Parses the lists directly from the bytes of the input, without splitting it into lines.
+pub fn parse_bytes(input: &[u8]) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    let mut parser = ListParser::new();
+    parser.feed(input)?;
+    parser.finish()
+}
+
+/// Streams the lists from `reader` in blocks, so the input never has to be in memory as a whole.
+pub fn read_lists(mut reader: impl Read) -> io::Result<(Vec<u64>, Vec<u64>)> {
+    let invalid_data = |err| io::Error::new(io::ErrorKind::InvalidData, err);
+
+    let mut parser = ListParser::new();
+    let mut block = vec![0; 1 << 16];
+    loop {
+        match reader.read(&mut block) {
+            Ok(0) => break,
+            Ok(len) => parser.feed(&block[..len]).map_err(invalid_data)?,
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    parser.finish().map_err(invalid_data)
+}
+
+/// Both lists sorted, ready for [`total_distance`] and [`similarity_score`].
+pub fn sorted<T: LocationId>((mut a_list, mut b_list): (Vec<T>, Vec<T>)) -> (Vec<T>, Vec<T>) {
+    T::sort(&mut a_list);
+    T::sort(&mut b_list);
+    (a_list, b_list)
+}
+
+/// The line based parser for any ID type, like `i64` for negative IDs.
+pub fn parse_as<T: FromStr>(input: &str) -> Result<(Vec<T>, Vec<T>), ParseError> {
+    let pairs = parse::lines(input, |line| {
+        let line = line.split_ascii_whitespace().collect::<Vec<_>>();
+        if line.len() != 2 {
+            return Err(ParseError::new("expected two numbers"));
+        }
+        let a: T = parse::number(line[0])?;
+        let b: T = parse::number(line[1])?;
+        Ok((a, b))
+    })?;
+
+    Ok(pairs.into_iter().unzip())
+}
+
+/// The line based parser, the baseline of the benchmarks.
+pub fn parse(input: &str) -> Result<(Vec<u64>, Vec<u64>), ParseError> {
+    parse_as(input)
+}
+
+/// Day 1 with location IDs of type `T`, the puzzle input fits into `u64`.
+pub struct Day1<T = u64>(PhantomData<T>);
+
+impl<T: LocationId> Solution for Day1<T> {
+    const DAY: u8 = 1;
+
+    /// Both lists, sorted.
+    type Input = (Vec<T>, Vec<T>);
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(sorted(T::parse_lists(input)?))
+    }
+
+    fn part1((a_sorted, b_sorted): &Self::Input) -> String {
+        total_distance(a_sorted, b_sorted).to_string()
+    }
+
+    fn part2((a_sorted, b_sorted): &Self::Input) -> Option<String> {
+        Some(similarity_score(a_sorted, b_sorted).to_string())
+    }
+}
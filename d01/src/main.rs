@@ -1,48 +1,95 @@
-use std::{collections::HashMap, fs, iter::zip};
-
-fn solve_a(mut a_list: Vec<u64>, mut b_list: Vec<u64>) -> u64 {
-    a_list.sort();
-    b_list.sort();
-    zip(a_list.iter(), b_list.iter())
-        .map(|(&a, &b)| a.abs_diff(b))
-        .sum()
-}
+use std::{
+    io::{self, BufRead, Write},
+    path::Path,
+};
 
-fn solve_b(a_list: Vec<u64>, b_list: Vec<u64>) -> u64 {
-    let mut b_counts = HashMap::new();
-    for b in b_list {
-        *b_counts.entry(b).or_insert(0) += 1;
-    }
+use day1::{Day1, Edit, LocationId, OnlineLists};
+use num_bigint::BigInt;
+use runner::exit_with;
 
-    let mut sum = 0;
-    for a in a_list {
-        if b_counts.contains_key(&a) {
-            sum += *b_counts.get(&a).unwrap() * a;
+const USAGE: &str = "usage: day1 [INPUT] [--ids u64|i64|u128|big] [--stream]";
+
+/// Reads `+ a b` and `- a b` edits from stdin and prints both answers after every one of them,
+/// starting from the lists of `selection` or from empty lists.
+fn stream<T: LocationId>(day_dir: &Path, selection: Option<&str>) {
+    let mut lists = match selection {
+        Some(selection) => {
+            let (selected, input) = runner::load_selection(day_dir, selection);
+            let parsed = T::parse_lists(&runner::text::normalize(&input)).unwrap_or_else(|err| {
+                exit_with(format!(
+                    "Cannot parse input {}: {err}",
+                    selected.file.display()
+                ))
+            });
+            OnlineLists::from_lists(parsed)
         }
-    }
+        None => OnlineLists::new(),
+    };
 
-    sum
+    let mut stdout = io::stdout().lock();
+    let mut print_answers = |lists: &OnlineLists<T>| {
+        writeln!(
+            stdout,
+            "{} {}",
+            lists.total_distance(),
+            lists.similarity_score()
+        )
+        .and_then(|()| stdout.flush())
+        .unwrap_or_else(|err| exit_with(format!("Cannot write the answers: {err}")));
+    };
+    print_answers(&lists);
+
+    for (line_idx, line) in io::stdin().lock().lines().enumerate() {
+        let line = line.unwrap_or_else(|err| exit_with(format!("Cannot read the edits: {err}")));
+        if line.trim().is_empty() {
+            continue;
+        }
+        Edit::parse(&line)
+            .and_then(|edit| edit.apply(&mut lists))
+            .unwrap_or_else(|err| exit_with(err.in_line(line_idx)));
+        print_answers(&lists);
+    }
 }
 
 fn main() {
-    let input = fs::read_to_string("input.txt").unwrap();
+    let day_dir = env!("CARGO_MANIFEST_DIR");
 
-    let mut a_list = Vec::new();
-    let mut b_list = Vec::new();
-
-    for line in input.lines() {
-        let line = line.split_ascii_whitespace().collect::<Vec<_>>();
-        assert!(line.len() == 2);
-        let a: u64 = line[0].parse().unwrap();
-        let b: u64 = line[1].parse().unwrap();
-
-        a_list.push(a);
-        b_list.push(b);
+    let mut selection = None;
+    let mut ids = "u64".to_string();
+    let mut stream_edits = false;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--stream" {
+            stream_edits = true;
+        } else if arg == "--ids" {
+            ids = args.next().unwrap_or_else(|| exit_with(USAGE));
+        } else if arg.starts_with("--") {
+            exit_with(USAGE);
+        } else {
+            selection = Some(arg);
+        }
     }
 
-    let output_a = solve_a(a_list.clone(), b_list.clone());
-    let output_b = solve_b(a_list, b_list);
+    if stream_edits {
+        let day_dir = Path::new(day_dir);
+        let selection = selection.as_deref();
+        match ids.as_str() {
+            "u64" => stream::<u64>(day_dir, selection),
+            "i64" => stream::<i64>(day_dir, selection),
+            "u128" => stream::<u128>(day_dir, selection),
+            "big" => stream::<BigInt>(day_dir, selection),
+            _ => exit_with(format!("unknown ID type {ids:?}, {USAGE}")),
+        }
+        return;
+    }
+    let selection = selection.as_deref().unwrap_or("input");
 
-    println!("Task1 = {output_a}");
-    println!("Task2 = {output_b}");
+    // the IDs of the puzzle fit into u64, the other types are for generated inputs
+    match ids.as_str() {
+        "u64" => runner::run_selection::<Day1>(day_dir, selection),
+        "i64" => runner::run_selection::<Day1<i64>>(day_dir, selection),
+        "u128" => runner::run_selection::<Day1<u128>>(day_dir, selection),
+        "big" => runner::run_selection::<Day1<BigInt>>(day_dir, selection),
+        _ => exit_with(format!("unknown ID type {ids:?}, {USAGE}")),
+    }
 }
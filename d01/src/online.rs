@@ -0,0 +1,165 @@
+//! Both answers kept up to date while pairs are added to and removed from the lists, without
+//! sorting them again.
+//!
+//! Adding a pair inserts its IDs at ranks `r` and `s` of the sorted lists. The pairs below
+//! `min(r, s)` stay the same and the ones above `max(r, s)` only move up by one, so just the
+//! pairs in between change partners. Removing a pair is the same backwards. The similarity only
+//! changes by the removed or added ID times its count in the other list.
+//!
+//! So an edit costs `O(log n)` for the ranks and the similarity, but `O(|r - s|)` for the total
+//! distance, which is `Θ(n)` for random edits. The online lists save re-sorting the lists after
+//! every edit, not the linear pass over them. The distance can't be kept in subtree aggregates
+//! like the sizes, because the pairs between the ranks change partners.
+
+use runner::{parse, ParseError};
+
+use crate::{ost::OrderStatisticTree, similarity_score, sorted, total_distance, LocationId};
+
+/// The two lists as sorted multisets, with the total distance and the similarity score.
+pub struct OnlineLists<T: LocationId = u64> {
+    a_list: OrderStatisticTree<T>,
+    b_list: OrderStatisticTree<T>,
+    distance: T::Total,
+    similarity: T::Total,
+}
+
+impl<T: LocationId> Default for OnlineLists<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: LocationId> OnlineLists<T> {
+    pub fn new() -> Self {
+        Self {
+            a_list: OrderStatisticTree::new(),
+            b_list: OrderStatisticTree::new(),
+            distance: T::Total::default(),
+            similarity: T::Total::default(),
+        }
+    }
+
+    /// Starts from the parsed lists, the answers are computed once the usual way.
+    pub fn from_lists(lists: (Vec<T>, Vec<T>)) -> Self {
+        let (a_sorted, b_sorted) = sorted(lists);
+        let mut online = Self {
+            distance: total_distance(&a_sorted, &b_sorted),
+            similarity: similarity_score(&a_sorted, &b_sorted),
+            ..Self::new()
+        };
+        for (a, b) in a_sorted.into_iter().zip(b_sorted) {
+            online.a_list.insert(a);
+            online.b_list.insert(b);
+        }
+        online
+    }
+
+    /// The number of pairs.
+    pub fn len(&self) -> usize {
+        self.a_list.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.a_list.is_empty()
+    }
+
+    /// The answer of part 1 for the current lists.
+    pub fn total_distance(&self) -> &T::Total {
+        &self.distance
+    }
+
+    /// The answer of part 2 for the current lists.
+    pub fn similarity_score(&self) -> &T::Total {
+        &self.similarity
+    }
+
+    /// Adds `a` to the left and `b` to the right list. Takes `O(log n)` plus `O(1)` for every pair
+    /// between the ranks of `a` and `b`, so `O(n)` in the worst case.
+    pub fn insert(&mut self, a: T, b: T) {
+        let (lo, hi) = min_max(self.a_list.rank(&a), self.b_list.rank(&b));
+        let old_segment = self.segment_distance(lo, hi - lo);
+
+        self.similarity += a.similarity(self.b_list.count(&a) as u64);
+        self.a_list.insert(a);
+        self.similarity += b.similarity(self.a_list.count(&b) as u64);
+        self.b_list.insert(b);
+
+        // added first, so the unsigned totals never go below zero
+        self.distance += self.segment_distance(lo, hi - lo + 1);
+        self.distance -= old_segment;
+    }
+
+    /// Removes `a` from the left and `b` from the right list, returns `false` and leaves the
+    /// lists as they are if either isn't in its list. Takes as long as [`OnlineLists::insert`].
+    pub fn remove(&mut self, a: &T, b: &T) -> bool {
+        if self.a_list.count(a) == 0 || self.b_list.count(b) == 0 {
+            return false;
+        }
+
+        let (lo, hi) = min_max(self.a_list.rank(a), self.b_list.rank(b));
+        let old_segment = self.segment_distance(lo, hi - lo + 1);
+
+        self.b_list.remove(b);
+        self.similarity -= b.similarity(self.a_list.count(b) as u64);
+        self.a_list.remove(a);
+        self.similarity -= a.similarity(self.b_list.count(a) as u64);
+
+        self.distance += self.segment_distance(lo, hi - lo);
+        self.distance -= old_segment;
+        true
+    }
+
+    /// The distances of the `len` pairs from rank `start` on.
+    fn segment_distance(&self, start: usize, len: usize) -> T::Total {
+        let mut sum = T::Total::default();
+        let pairs = self
+            .a_list
+            .iter_from(start)
+            .zip(self.b_list.iter_from(start));
+        for (a, b) in pairs.take(len) {
+            sum += a.distance(b);
+        }
+        sum
+    }
+}
+
+fn min_max(r: usize, s: usize) -> (usize, usize) {
+    (r.min(s), r.max(s))
+}
+
+/// A line of the edit stream, `+ a b` adds and `- a b` removes a pair.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Edit<T> {
+    Add(T, T),
+    Remove(T, T),
+}
+
+impl<T: LocationId> Edit<T> {
+    pub fn parse(line: &str) -> Result<Self, ParseError> {
+        let words = line.split_ascii_whitespace().collect::<Vec<_>>();
+        let [op, a, b] = words[..] else {
+            return Err(ParseError::new(
+                "expected an edit like \"+ 3 4\" or \"- 3 4\"",
+            ));
+        };
+        let (a, b) = (parse::number(a)?, parse::number(b)?);
+        match op {
+            "+" => Ok(Edit::Add(a, b)),
+            "-" => Ok(Edit::Remove(a, b)),
+            _ => Err(ParseError::new(format!("unknown edit {op:?}"))),
+        }
+    }
+
+    /// Applies the edit to `lists`, removing a pair that isn't in them is an error.
+    pub fn apply(self, lists: &mut OnlineLists<T>) -> Result<(), ParseError> {
+        match self {
+            Edit::Add(a, b) => lists.insert(a, b),
+            Edit::Remove(a, b) => {
+                if !lists.remove(&a, &b) {
+                    return Err(ParseError::new("the pair to remove isn't in the lists"));
+                }
+            }
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,287 @@
+//! An order-statistic tree: a sorted multiset that also answers the rank of a value and the value
+//! at a rank in `O(log n)`. It is a treap with the sizes of the subtrees in its nodes, duplicates
+//! share a node.
+
+/// Index of a node in [`OrderStatisticTree::nodes`].
+type Link = Option<usize>;
+
+struct Node<T> {
+    value: T,
+    count: usize,
+    /// The number of values in the subtree, counting duplicates.
+    size: usize,
+    priority: u64,
+    left: Link,
+    right: Link,
+}
+
+pub struct OrderStatisticTree<T> {
+    nodes: Vec<Node<T>>,
+    free: Vec<usize>,
+    root: Link,
+    rng_state: u64,
+}
+
+impl<T: Ord> Default for OrderStatisticTree<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Ord> OrderStatisticTree<T> {
+    pub fn new() -> Self {
+        Self {
+            nodes: Vec::new(),
+            free: Vec::new(),
+            root: None,
+            rng_state: 0x9E37_79B9_7F4A_7C15,
+        }
+    }
+
+    pub fn len(&self) -> usize {
+        self.size(self.root)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.root.is_none()
+    }
+
+    /// The number of values smaller than `value`, the index it is inserted at.
+    pub fn rank(&self, value: &T) -> usize {
+        let mut rank = 0;
+        let mut link = self.root;
+        while let Some(idx) = link {
+            let node = &self.nodes[idx];
+            if *value <= node.value {
+                link = node.left;
+            } else {
+                rank += self.size(node.left) + node.count;
+                link = node.right;
+            }
+        }
+        rank
+    }
+
+    /// How often `value` is in the tree.
+    pub fn count(&self, value: &T) -> usize {
+        self.find(value).map_or(0, |idx| self.nodes[idx].count)
+    }
+
+    /// The value at `rank` in ascending order, `None` past the end.
+    pub fn select(&self, mut rank: usize) -> Option<&T> {
+        let mut link = self.root;
+        while let Some(idx) = link {
+            let node = &self.nodes[idx];
+            let left_size = self.size(node.left);
+            if rank < left_size {
+                link = node.left;
+            } else if rank < left_size + node.count {
+                return Some(&node.value);
+            } else {
+                rank -= left_size + node.count;
+                link = node.right;
+            }
+        }
+        None
+    }
+
+    /// The values from `rank` on in ascending order, duplicates repeated.
+    pub fn iter_from(&self, mut rank: usize) -> Iter<'_, T> {
+        let mut iter = Iter {
+            tree: self,
+            pending: Vec::new(),
+            current: None,
+        };
+
+        let mut link = self.root;
+        while let Some(idx) = link {
+            let node = &self.nodes[idx];
+            let left_size = self.size(node.left);
+            if rank < left_size {
+                iter.pending.push(idx);
+                link = node.left;
+            } else if rank < left_size + node.count {
+                iter.current = Some((idx, left_size + node.count - rank));
+                break;
+            } else {
+                rank -= left_size + node.count;
+                link = node.right;
+            }
+        }
+        iter
+    }
+
+    pub fn insert(&mut self, value: T) {
+        if self.find(&value).is_some() {
+            self.add_to_path(&value, |count| count + 1);
+            return;
+        }
+
+        let (less, rest) = self.split(self.root, |node| node.value < value);
+
+        let node = Node {
+            value,
+            count: 1,
+            size: 1,
+            priority: self.next_priority(),
+            left: None,
+            right: None,
+        };
+        let idx = match self.free.pop() {
+            Some(idx) => {
+                self.nodes[idx] = node;
+                idx
+            }
+            None => {
+                self.nodes.push(node);
+                self.nodes.len() - 1
+            }
+        };
+
+        let less = self.merge(less, Some(idx));
+        self.root = self.merge(less, rest);
+    }
+
+    /// Removes one occurrence of `value`, returns whether there was one.
+    pub fn remove(&mut self, value: &T) -> bool {
+        match self.find(value) {
+            None => false,
+            Some(idx) if self.nodes[idx].count > 1 => {
+                self.add_to_path(value, |count| count - 1);
+                true
+            }
+            Some(idx) => {
+                let (less, rest) = self.split(self.root, |node| node.value < *value);
+                let (_, greater) = self.split(rest, |node| node.value <= *value);
+                self.nodes[idx].left = None;
+                self.nodes[idx].right = None;
+                self.free.push(idx);
+                self.root = self.merge(less, greater);
+                true
+            }
+        }
+    }
+
+    fn size(&self, link: Link) -> usize {
+        link.map_or(0, |idx| self.nodes[idx].size)
+    }
+
+    fn find(&self, value: &T) -> Link {
+        let mut link = self.root;
+        while let Some(idx) = link {
+            let node = &self.nodes[idx];
+            link = match value.cmp(&node.value) {
+                std::cmp::Ordering::Less => node.left,
+                std::cmp::Ordering::Greater => node.right,
+                std::cmp::Ordering::Equal => return Some(idx),
+            };
+        }
+        None
+    }
+
+    /// Changes the count of the existing `value` with `change`, and the sizes on the way to it.
+    fn add_to_path(&mut self, value: &T, change: impl Fn(usize) -> usize) {
+        let mut link = self.root;
+        while let Some(idx) = link {
+            let node = &mut self.nodes[idx];
+            node.size = change(node.size);
+            link = match value.cmp(&node.value) {
+                std::cmp::Ordering::Less => node.left,
+                std::cmp::Ordering::Greater => node.right,
+                std::cmp::Ordering::Equal => {
+                    node.count = change(node.count);
+                    None
+                }
+            };
+        }
+    }
+
+    fn update_size(&mut self, idx: usize) {
+        let node = &self.nodes[idx];
+        self.nodes[idx].size = node.count + self.size(node.left) + self.size(node.right);
+    }
+
+    /// Splits the tree into the nodes that fulfill `goes_left`, which must hold for a prefix of
+    /// the sorted values, and the rest.
+    fn split(&mut self, link: Link, goes_left: impl Fn(&Node<T>) -> bool + Copy) -> (Link, Link) {
+        let Some(idx) = link else {
+            return (None, None);
+        };
+
+        if goes_left(&self.nodes[idx]) {
+            let (left, right) = self.split(self.nodes[idx].right, goes_left);
+            self.nodes[idx].right = left;
+            self.update_size(idx);
+            (Some(idx), right)
+        } else {
+            let (left, right) = self.split(self.nodes[idx].left, goes_left);
+            self.nodes[idx].left = right;
+            self.update_size(idx);
+            (left, Some(idx))
+        }
+    }
+
+    /// Joins two trees, all values of `left` are smaller than the ones of `right`.
+    fn merge(&mut self, left: Link, right: Link) -> Link {
+        match (left, right) {
+            (None, link) | (link, None) => link,
+            (Some(left_idx), Some(right_idx)) => {
+                if self.nodes[left_idx].priority > self.nodes[right_idx].priority {
+                    let merged = self.merge(self.nodes[left_idx].right, right);
+                    self.nodes[left_idx].right = merged;
+                    self.update_size(left_idx);
+                    Some(left_idx)
+                } else {
+                    let merged = self.merge(left, self.nodes[right_idx].left);
+                    self.nodes[right_idx].left = merged;
+                    self.update_size(right_idx);
+                    Some(right_idx)
+                }
+            }
+        }
+    }
+
+    /// xorshift64, the priorities only need to be spread evenly to keep the treap balanced.
+    fn next_priority(&mut self) -> u64 {
+        self.rng_state ^= self.rng_state << 13;
+        self.rng_state ^= self.rng_state >> 7;
+        self.rng_state ^= self.rng_state << 17;
+        self.rng_state
+    }
+}
+
+/// In-order iterator of an [`OrderStatisticTree`], see [`OrderStatisticTree::iter_from`].
+pub struct Iter<'a, T> {
+    tree: &'a OrderStatisticTree<T>,
+    /// The nodes whose left subtree is being visited, the innermost last.
+    pending: Vec<usize>,
+    /// The node being visited and how many of its duplicates are left.
+    current: Option<(usize, usize)>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        loop {
+            match &mut self.current {
+                Some((idx, remaining)) if *remaining > 0 => {
+                    *remaining -= 1;
+                    return Some(&self.tree.nodes[*idx].value);
+                }
+                Some((idx, _)) => {
+                    let mut link = self.tree.nodes[*idx].right;
+                    while let Some(idx) = link {
+                        self.pending.push(idx);
+                        link = self.tree.nodes[idx].left;
+                    }
+                    self.current = None;
+                }
+                None => {
+                    let idx = self.pending.pop()?;
+                    self.current = Some((idx, self.tree.nodes[idx].count));
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,76 @@
+//! Sorting of the location IDs without comparisons. The IDs are bounded numbers, so a counting
+//! sort (for dense lists) or an LSD radix sort over the bytes that actually differ beats
+//! `sort_unstable` on large lists.
+
+/// Lists up to this length are sorted with `sort_unstable`, the histograms don't pay off below.
+const SMALL_LIST: usize = 256;
+
+const RADIX_BITS: u32 = 8;
+const BUCKETS: usize = 1 << RADIX_BITS;
+
+/// Sorts `list` in ascending order.
+pub fn sort_locations(list: &mut [u64]) {
+    if list.len() <= SMALL_LIST {
+        list.sort_unstable();
+        return;
+    }
+
+    let (min, max) = list.iter().fold((u64::MAX, u64::MIN), |(min, max), &id| {
+        (min.min(id), max.max(id))
+    });
+    let range = max - min;
+
+    if range < list.len() as u64 {
+        counting_sort(list, min, range as usize);
+    } else {
+        radix_sort(list, min, range);
+    }
+}
+
+/// Sorts a list whose IDs all lie in `min..=min + range`, with `range` smaller than the list.
+fn counting_sort(list: &mut [u64], min: u64, range: usize) {
+    let mut counts = vec![0usize; range + 1];
+    for &id in list.iter() {
+        counts[(id - min) as usize] += 1;
+    }
+
+    let mut sorted = list.iter_mut();
+    for (offset, count) in counts.into_iter().enumerate() {
+        for slot in sorted.by_ref().take(count) {
+            *slot = min + offset as u64;
+        }
+    }
+}
+
+/// LSD radix sort of the offsets to `min`, only over the bytes that are needed for `range`.
+fn radix_sort(list: &mut [u64], min: u64, range: u64) {
+    let num_passes = (u64::BITS - range.leading_zeros()).div_ceil(RADIX_BITS);
+
+    let mut keys: Vec<u64> = list.iter().map(|&id| id - min).collect();
+    let mut buffer = vec![0; keys.len()];
+
+    for pass in 0..num_passes {
+        let shift = pass * RADIX_BITS;
+        let digit = |key: u64| ((key >> shift) as usize) & (BUCKETS - 1);
+
+        let mut offsets = [0usize; BUCKETS];
+        for &key in &keys {
+            offsets[digit(key)] += 1;
+        }
+        let mut next = 0;
+        for offset in &mut offsets {
+            (*offset, next) = (next, next + *offset);
+        }
+
+        for &key in &keys {
+            let bucket = &mut offsets[digit(key)];
+            buffer[*bucket] = key;
+            *bucket += 1;
+        }
+        std::mem::swap(&mut keys, &mut buffer);
+    }
+
+    for (slot, key) in list.iter_mut().zip(keys) {
+        *slot = key + min;
+    }
+}
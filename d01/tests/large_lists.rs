@@ -0,0 +1,91 @@
+use day1::{
+    parse, parse_as, parse_bytes, read_lists, similarity_score, solve_a, solve_b, sorted,
+    total_distance,
+};
+use num_bigint::BigInt;
+use proptest::prelude::*;
+
+/// Lists with many duplicates or a wide range, so both the counting and the radix sort run.
+fn lists_input() -> impl Strategy<Value = String> {
+    prop_oneof![Just(100_u64), Just(1_000), Just(1 << 40)]
+        .prop_flat_map(|max_id| proptest::collection::vec((0..max_id, 0..max_id), 1..5_000))
+        .prop_map(|pairs| pairs.iter().map(|(a, b)| format!("{a}   {b}\n")).collect())
+}
+
+/// Hands out the input a few bytes at a time, so numbers and lines are split between reads.
+struct Trickle<'a>(&'a [u8]);
+
+impl std::io::Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.0.len().min(buf.len()).min(3);
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+proptest! {
+    #[test]
+    fn matches_the_straightforward_solution(input in lists_input()) {
+        let (a_list, b_list) = parse(&input).unwrap();
+        prop_assert_eq!(
+            parse_bytes(input.as_bytes()).unwrap(),
+            (a_list.clone(), b_list.clone())
+        );
+        prop_assert_eq!(
+            read_lists(Trickle(input.as_bytes())).unwrap(),
+            (a_list.clone(), b_list.clone())
+        );
+
+        let (a_sorted, b_sorted) = sorted((a_list.clone(), b_list.clone()));
+        prop_assert_eq!(
+            total_distance(&a_sorted, &b_sorted),
+            solve_a(a_list.clone(), b_list.clone()) as u128
+        );
+        prop_assert_eq!(
+            similarity_score(&a_sorted, &b_sorted),
+            solve_b(a_list, b_list) as u128
+        );
+    }
+}
+
+#[test]
+fn byte_parser_rejects_what_the_line_parser_rejects() {
+    for input in [
+        "1 2 3\n",
+        "1\n",
+        "1 2\n\n3 4\n",
+        "1 x\n",
+        "99999999999999999999 1\n",
+    ] {
+        assert!(parse(input).is_err(), "{input:?}");
+        assert!(parse_bytes(input.as_bytes()).is_err(), "{input:?}");
+    }
+    assert_eq!(
+        parse_bytes(b"1 2\r\n3 4").unwrap(),
+        (vec![1, 3], vec![2, 4])
+    );
+}
+
+#[test]
+fn wide_and_signed_ids() {
+    let input = "-3   4\n18446744073709551616   -1\n-3   -3\n";
+    assert!(parse(input).is_err());
+    assert!(parse_as::<i64>(input).is_err());
+
+    let (a_sorted, b_sorted) = sorted(parse_as::<BigInt>(input).unwrap());
+    assert_eq!(
+        total_distance(&a_sorted, &b_sorted),
+        BigInt::from(u64::MAX - 1)
+    );
+    assert_eq!(similarity_score(&a_sorted, &b_sorted), BigInt::from(-6));
+
+    // the similarity of the same ID many times exceeds u64, but not the sum type
+    let input = format!("{0}   {0}\n", u64::MAX).repeat(3);
+    let (a_sorted, b_sorted) = sorted(parse_as::<u64>(&input).unwrap());
+    assert_eq!(similarity_score(&a_sorted, &b_sorted), u64::MAX as u128 * 9);
+
+    let (a_sorted, b_sorted) = sorted(parse_as::<i64>("-5   3\n3   -5\n-5   4\n").unwrap());
+    assert_eq!(total_distance(&a_sorted, &b_sorted), 9);
+    assert_eq!(similarity_score(&a_sorted, &b_sorted), -5 * 2 + 3);
+}
@@ -0,0 +1,124 @@
+use day1::{
+    parse_as, similarity_score, sorted, total_distance, Edit, LocationId, OnlineLists,
+    OrderStatisticTree,
+};
+use proptest::{prelude::*, sample::Index};
+
+#[derive(Clone, Debug)]
+enum Op {
+    Insert(u64, u64),
+    /// Removes one of the pairs inserted so far.
+    Remove(Index),
+}
+
+/// Edits with IDs below `max_id`, small ranges give many duplicates.
+fn ops(max_id: u64) -> impl Strategy<Value = Vec<Op>> {
+    proptest::collection::vec(
+        prop_oneof![
+            2 => (0..max_id, 0..max_id).prop_map(|(a, b)| Op::Insert(a, b)),
+            1 => any::<Index>().prop_map(Op::Remove),
+        ],
+        0..300,
+    )
+}
+
+/// Applies `ops` and compares the answers after every edit with the ones of the sorted lists.
+fn check_edits<T: LocationId + std::fmt::Debug>(
+    to_id: impl Fn(u64) -> T,
+    ops: &[Op],
+) -> Result<(), TestCaseError> {
+    let mut pairs: Vec<(T, T)> = Vec::new();
+    let mut online = OnlineLists::<T>::new();
+
+    for op in ops {
+        match *op {
+            Op::Insert(a, b) => {
+                let (a, b) = (to_id(a), to_id(b));
+                online.insert(a.clone(), b.clone());
+                pairs.push((a, b));
+            }
+            Op::Remove(_) if pairs.is_empty() => continue,
+            Op::Remove(idx) => {
+                let (a, b) = pairs.swap_remove(idx.index(pairs.len()));
+                prop_assert!(online.remove(&a, &b));
+            }
+        }
+
+        let (a_sorted, b_sorted) = sorted(pairs.iter().cloned().unzip());
+        prop_assert_eq!(online.len(), pairs.len());
+        prop_assert_eq!(
+            online.total_distance().to_string(),
+            total_distance(&a_sorted, &b_sorted).to_string()
+        );
+        prop_assert_eq!(
+            online.similarity_score().to_string(),
+            similarity_score(&a_sorted, &b_sorted).to_string()
+        );
+    }
+    Ok(())
+}
+
+proptest! {
+    #[test]
+    fn edits_match_the_sorted_lists(ops in ops(10)) {
+        check_edits(|id| id, &ops)?;
+    }
+
+    #[test]
+    fn edits_of_wide_ids_match_the_sorted_lists(ops in ops(1 << 40)) {
+        check_edits(|id| id, &ops)?;
+    }
+
+    #[test]
+    fn edits_of_signed_ids_match_the_sorted_lists(ops in ops(100)) {
+        check_edits(|id| id as i64 - 50, &ops)?;
+    }
+}
+
+#[test]
+fn starts_from_the_lists() {
+    let lists = parse_as::<u64>("3   4\n4   3\n2   5\n1   3\n3   9\n3   3\n").unwrap();
+    let mut online = OnlineLists::from_lists(lists);
+    assert_eq!(
+        (*online.total_distance(), *online.similarity_score()),
+        (11, 31)
+    );
+
+    Edit::parse("- 3 9").unwrap().apply(&mut online).unwrap();
+    Edit::parse("+ 9 9").unwrap().apply(&mut online).unwrap();
+    assert_eq!(
+        (*online.total_distance(), *online.similarity_score()),
+        (5, 31)
+    );
+}
+
+#[test]
+fn removing_a_missing_pair_fails() {
+    let mut online = OnlineLists::<u64>::new();
+    online.insert(1, 2);
+    assert!(!online.remove(&2, &2));
+    assert!(Edit::parse("- 1 1").unwrap().apply(&mut online).is_err());
+    assert!(Edit::<u64>::parse("* 1 2").is_err());
+    assert!(Edit::<u64>::parse("+ 1").is_err());
+    assert_eq!(
+        (*online.total_distance(), *online.similarity_score()),
+        (1, 0)
+    );
+}
+
+#[test]
+fn tree_ranks_and_selects() {
+    let mut tree = OrderStatisticTree::new();
+    for value in [5, 1, 5, 3, 5, 9] {
+        tree.insert(value);
+    }
+    assert_eq!(tree.len(), 6);
+    assert_eq!((tree.rank(&5), tree.count(&5), tree.rank(&6)), (2, 3, 5));
+    assert_eq!(tree.select(4), Some(&5));
+    assert_eq!(tree.iter_from(1).collect::<Vec<_>>(), [&3, &5, &5, &5, &9]);
+
+    assert!(tree.remove(&5));
+    assert!(tree.remove(&1));
+    assert!(!tree.remove(&1));
+    assert_eq!(tree.iter_from(0).collect::<Vec<_>>(), [&3, &5, &5, &9]);
+}
@@ -0,0 +1,210 @@
+//! Why the reports are safe or not, for auditing the counts: the first pair of levels that breaks
+//! the rules and the levels the Problem Dampener removes, as a table or as JSON.
+
+use std::fmt::Display;
+
+use serde::Serialize;
+
+use crate::{Direction, RuleProfile, ShortReports};
+
+/// What is wrong with a pair of neighboring levels, or with the whole report.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Reason {
+    /// Less than two levels, with rules that don't allow that.
+    TooShort,
+    /// Two equal levels, with rules that don't allow plateaus.
+    ZeroStep,
+    /// A step against the direction of the report.
+    DirectionChange,
+    StepTooLarge,
+    StepTooSmall,
+}
+
+impl Display for Reason {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let reason = match self {
+            Reason::TooShort => "too short",
+            Reason::ZeroStep => "zero step",
+            Reason::DirectionChange => "direction change",
+            Reason::StepTooLarge => "step too large",
+            Reason::StepTooSmall => "step too small",
+        };
+        f.write_str(reason)
+    }
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Problem {
+    /// The indices of the two levels, `None` for a report that is too short.
+    pub levels: Option<[usize; 2]>,
+    pub reason: Reason,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq, Serialize)]
+pub struct Diagnosis {
+    /// The line of the report in the input, starting at 1.
+    pub line: usize,
+    pub levels: Vec<u64>,
+    pub safe: bool,
+    /// The first problem of an unsafe report.
+    pub problem: Option<Problem>,
+    /// The indices of the levels the Problem Dampener removes to make the report safe, none for a
+    /// safe report and `None` if the dampener can't make it safe.
+    pub removed: Option<Vec<usize>>,
+}
+
+/// What is wrong with `next` following `level` in a report going in `direction`.
+fn check_step(rules: &RuleProfile, level: u64, next: u64, direction: Direction) -> Option<Reason> {
+    let step = level.abs_diff(next);
+    if step == 0 {
+        return (!rules.plateaus).then_some(Reason::ZeroStep);
+    }
+
+    let is_right_way = match direction {
+        Direction::Ascending => level < next,
+        Direction::Descending => level > next,
+        Direction::Monotonic | Direction::Free => true,
+    };
+    if !is_right_way {
+        Some(Reason::DirectionChange)
+    } else if step > rules.max_step {
+        Some(Reason::StepTooLarge)
+    } else if step < rules.min_step {
+        Some(Reason::StepTooSmall)
+    } else {
+        None
+    }
+}
+
+/// The first problem of `report`, `None` if it is safe. A monotonic report goes in the direction
+/// of its first step between different levels.
+pub fn first_problem(rules: &RuleProfile, report: &[u64]) -> Option<Problem> {
+    if report.len() < 2 {
+        return (rules.short_reports == ShortReports::Unsafe).then_some(Problem {
+            levels: None,
+            reason: Reason::TooShort,
+        });
+    }
+
+    let direction = match rules.direction {
+        Direction::Monotonic => match report.windows(2).find(|pair| pair[0] != pair[1]) {
+            Some(pair) if pair[0] > pair[1] => Direction::Descending,
+            _ => Direction::Ascending,
+        },
+        direction => direction,
+    };
+
+    report.windows(2).enumerate().find_map(|(idx, pair)| {
+        check_step(rules, pair[0], pair[1], direction).map(|reason| Problem {
+            levels: Some([idx, idx + 1]),
+            reason,
+        })
+    })
+}
+
+/// Diagnoses every report with `rules`, the dampener removes up to `rules.dampener` levels.
+pub fn diagnose(rules: &RuleProfile, reports: &[Vec<u64>]) -> Vec<Diagnosis> {
+    reports
+        .iter()
+        .enumerate()
+        .map(|(line_idx, report)| {
+            let problem = first_problem(rules, report);
+            let removed = if problem.is_none() {
+                Some(Vec::new())
+            } else {
+                rules
+                    .longest_safe_levels(report)
+                    .filter(|kept| report.len() - kept.len() <= rules.dampener)
+                    .map(|kept| {
+                        (0..report.len())
+                            .filter(|idx| kept.binary_search(idx).is_err())
+                            .collect()
+                    })
+            };
+
+            Diagnosis {
+                line: line_idx + 1,
+                levels: report.clone(),
+                safe: problem.is_none(),
+                problem,
+                removed,
+            }
+        })
+        .collect()
+}
+
+/// The diagnoses as a markdown table like the one of `aoc all`.
+pub fn format_table(diagnoses: &[Diagnosis]) -> String {
+    let header = ["line", "levels", "safe", "first problem", "dampener"].map(String::from);
+    let rows: Vec<[String; 5]> = diagnoses
+        .iter()
+        .map(|diagnosis| {
+            let levels: Vec<_> = diagnosis.levels.iter().map(u64::to_string).collect();
+            let problem = match &diagnosis.problem {
+                None => "-".to_string(),
+                Some(Problem {
+                    levels: Some([first, second]),
+                    reason,
+                }) => format!("{first}-{second}: {reason}"),
+                Some(Problem {
+                    levels: None,
+                    reason,
+                }) => reason.to_string(),
+            };
+            let dampener = match &diagnosis.removed {
+                None => "can't make it safe".to_string(),
+                Some(removed) if removed.is_empty() => "-".to_string(),
+                Some(removed) => {
+                    let removed: Vec<_> = removed
+                        .iter()
+                        .map(|&idx| format!("{idx} ({})", diagnosis.levels[idx]))
+                        .collect();
+                    format!("removed {}", removed.join(", "))
+                }
+            };
+
+            [
+                diagnosis.line.to_string(),
+                levels.join(" "),
+                if diagnosis.safe { "yes" } else { "no" }.to_string(),
+                problem,
+                dampener,
+            ]
+        })
+        .collect();
+
+    let mut widths = header.clone().map(|column| column.chars().count());
+    for row in &rows {
+        for (width, cell) in widths.iter_mut().zip(row) {
+            *width = (*width).max(cell.chars().count());
+        }
+    }
+
+    let format_row = |row: &[String; 5]| {
+        let cells: Vec<_> = row
+            .iter()
+            .zip(widths)
+            .enumerate()
+            .map(|(column, (cell, width))| match column {
+                // the line numbers are right aligned, everything else is text
+                0 => format!("{cell:>width$}"),
+                _ => format!("{cell:<width$}"),
+            })
+            .collect();
+        format!("| {} |\n", cells.join(" | "))
+    };
+
+    let separator: Vec<_> = widths.iter().map(|&width| "-".repeat(width)).collect();
+    let mut table = format_row(&header);
+    table += &format!("|-{}-|\n", separator.join("-|-"));
+    for row in &rows {
+        table += &format_row(row);
+    }
+    table
+}
+
+/// The diagnoses as a JSON array with an object per report.
+pub fn format_json(diagnoses: &[Diagnosis]) -> String {
+    serde_json::to_string_pretty(diagnoses).expect("the diagnoses are plain data")
+}
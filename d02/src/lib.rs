@@ -0,0 +1,63 @@
+use runner::{par, parse, ParseError, Solution};
+
+mod diagnostics;
+mod rules;
+
+pub use diagnostics::{
+    diagnose, first_problem, format_json, format_table, Diagnosis, Problem, Reason,
+};
+pub use rules::{Direction, RuleProfile, ShortReports};
+
+/// Whether `report` is safe with the puzzle rules.
+pub fn is_report_save(report: &[u64]) -> bool {
+    RuleProfile::PUZZLE.is_safe(report)
+}
+
+pub fn solve_a(reports: &[Vec<u64>]) -> u64 {
+    par::count(reports, |report| is_report_save(report))
+}
+
+/// The minimum number of levels that have to be removed to make `report` safe with the puzzle
+/// rules, a single level left over counts as safe.
+pub fn min_removals(report: &[u64]) -> usize {
+    RuleProfile::PUZZLE
+        .min_removals(report)
+        .expect("short reports are safe with the puzzle rules")
+}
+
+/// The number of reports that are safe with the puzzle rules after removing at most
+/// `max_removals` levels from each.
+pub fn count_safe_with(reports: &[Vec<u64>], max_removals: usize) -> u64 {
+    RuleProfile::PUZZLE.count_safe_with(reports, max_removals)
+}
+
+pub fn solve_b(reports: &[Vec<u64>]) -> u64 {
+    count_safe_with(reports, RuleProfile::PUZZLE.dampener)
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<u64>>, ParseError> {
+    parse::lines(input, |line| {
+        // reports with less than two levels are left to `RuleProfile::short_reports`
+        line.split_ascii_whitespace().map(parse::number).collect()
+    })
+}
+
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Vec<u64>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(reports: &Self::Input) -> String {
+        solve_a(reports).to_string()
+    }
+
+    fn part2(reports: &Self::Input) -> Option<String> {
+        Some(solve_b(reports).to_string())
+    }
+}
@@ -1,64 +1,63 @@
-fn is_report_save(report: &Vec<u64>) -> bool {
-    assert!(report.len() > 1);
-    let is_ascending = report[0] < report[1];
-    for i in 0..(report.len() - 1) {
-        if (report[i] < report[i + 1]) != is_ascending {
-            return false;
-        }
+use std::path::Path;
 
-        let diff = report[i].abs_diff(report[i + 1]);
-        if diff < 1 || diff > 3 {
-            return false;
-        }
-    }
-    true
+use day2::RuleProfile;
+use runner::exit_with;
+
+const USAGE: &str = "usage: day2 [INPUT] [--rules PROFILE] [--diagnose table|json]";
+
+fn read_reports(day_dir: &Path, selection: &str) -> Vec<Vec<u64>> {
+    let (selected, input) = runner::load_selection(day_dir, selection);
+    day2::parse(&runner::text::normalize(&input)).unwrap_or_else(|err| {
+        exit_with(format!(
+            "Cannot parse input {}: {err}",
+            selected.file.display()
+        ))
+    })
 }
 
-fn solve_a(reports: &Vec<Vec<u64>>) -> u64 {
-    let mut num_save_reports = 0;
-    for report in reports {
-        if is_report_save(report) {
-            num_save_reports += 1;
-        }
-    }
-    num_save_reports
+/// Counts the safe reports of `selection` with the rules of the profile, without and with the
+/// Problem Dampener. The expected answers are for the puzzle rules, so they aren't checked.
+fn run_with_rules(day_dir: &Path, selection: &str, rules: &RuleProfile) {
+    let reports = read_reports(day_dir, selection);
+    println!("Task1: {}", rules.count_safe_with(&reports, 0));
+    println!("Task2: {}", rules.count_safe_with(&reports, rules.dampener));
 }
 
-fn solve_b(reports: &Vec<Vec<u64>>) -> u64 {
-    let mut num_save_reports = 0;
-    for report in reports {
-        if is_report_save(report) {
-            num_save_reports += 1;
-            continue;
+fn main() {
+    let day_dir = env!("CARGO_MANIFEST_DIR");
+
+    let mut selection = "input".to_string();
+    let mut rules = None;
+    let mut diagnose_format = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        if arg == "--rules" {
+            let path = args.next().unwrap_or_else(|| exit_with(USAGE));
+            let profile = RuleProfile::load(Path::new(&path))
+                .unwrap_or_else(|err| exit_with(format!("Cannot load rules {path}: {err}")));
+            rules = Some(profile);
+        } else if arg == "--diagnose" {
+            diagnose_format = Some(args.next().unwrap_or_else(|| exit_with(USAGE)));
+        } else if arg.starts_with("--") {
+            exit_with(USAGE);
+        } else {
+            selection = arg;
         }
+    }
 
-        for i in 0..report.len() {
-            let mut dampened_report = report.clone();
-            dampened_report.remove(i);
-            if is_report_save(&dampened_report) {
-                num_save_reports += 1;
-                break;
-            }
+    if let Some(format) = diagnose_format {
+        let reports = read_reports(Path::new(day_dir), &selection);
+        let diagnoses = day2::diagnose(&rules.unwrap_or_default(), &reports);
+        match format.as_str() {
+            "table" => print!("{}", day2::format_table(&diagnoses)),
+            "json" => println!("{}", day2::format_json(&diagnoses)),
+            _ => exit_with(format!("unknown format {format:?}, {USAGE}")),
         }
+        return;
     }
-    num_save_reports
-}
-
-fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
 
-    let reports: Vec<Vec<u64>> = input
-        .lines()
-        .map(|line| {
-            line.split_ascii_whitespace()
-                .map(|num| num.parse().unwrap())
-                .collect::<Vec<u64>>()
-        })
-        .collect();
-
-    let output_a = solve_a(&reports);
-    let output_b = solve_b(&reports);
-
-    println!("Task1 = {output_a}");
-    println!("Task2 = {output_b}");
+    match rules {
+        Some(rules) => run_with_rules(Path::new(day_dir), &selection, &rules),
+        None => runner::run_selection::<day2::Day2>(day_dir, &selection),
+    }
 }
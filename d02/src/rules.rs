@@ -0,0 +1,250 @@
+//! What makes a report safe. The puzzle asks for strictly ascending or descending levels with
+//! steps of 1 to 3, other sensors may tolerate more, which a rule profile describes:
+//!
+//! ```toml
+//! min_step = 1
+//! max_step = 5
+//! plateaus = true         # equal neighboring levels are safe
+//! direction = "free"      # "monotonic", "ascending", "descending" or "free"
+//! short_reports = "safe"  # whether reports with less than two levels are safe
+//! dampener = 2            # how many levels the Problem Dampener may remove
+//! ```
+//!
+//! Missing keys keep the puzzle rules.
+
+use std::{collections::HashMap, io, path::Path};
+
+use runner::par;
+use serde::Deserialize;
+
+/// Reports up to this length are checked with the quadratic DP, which needs no hash map.
+const SMALL_REPORT: usize = 32;
+
+/// Step ranges up to this size are looked up in a hash map for long reports, wider ranges have
+/// too many possible previous levels and use the quadratic DP.
+const SMALL_STEP_RANGE: u64 = 8;
+
+/// The direction the levels of a safe report go in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    /// All ascending or all descending.
+    Monotonic,
+    Ascending,
+    Descending,
+    /// Every step may go either way.
+    Free,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ShortReports {
+    Safe,
+    Unsafe,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Deserialize)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuleProfile {
+    /// The smallest difference between neighboring levels, plateaus aside.
+    pub min_step: u64,
+    /// The largest difference between neighboring levels.
+    pub max_step: u64,
+    /// Whether neighboring levels may be equal, that doesn't change the direction.
+    pub plateaus: bool,
+    pub direction: Direction,
+    /// Whether reports with zero or one level are safe.
+    pub short_reports: ShortReports,
+    /// The number of levels part 2 may remove from a report.
+    pub dampener: usize,
+}
+
+impl Default for RuleProfile {
+    fn default() -> Self {
+        Self::PUZZLE
+    }
+}
+
+impl RuleProfile {
+    /// The rules of the puzzle.
+    pub const PUZZLE: Self = Self {
+        min_step: 1,
+        max_step: 3,
+        plateaus: false,
+        direction: Direction::Monotonic,
+        short_reports: ShortReports::Safe,
+        dampener: 1,
+    };
+
+    /// Reads a profile from a TOML file like the one above.
+    pub fn load(path: &Path) -> io::Result<Self> {
+        Self::from_toml(&std::fs::read_to_string(path)?)
+    }
+
+    pub fn from_toml(content: &str) -> io::Result<Self> {
+        let invalid_data = |message: String| io::Error::new(io::ErrorKind::InvalidData, message);
+
+        let profile: Self = toml::from_str(content).map_err(|err| invalid_data(err.to_string()))?;
+        if profile.min_step == 0 || profile.min_step > profile.max_step {
+            return Err(invalid_data(format!(
+                "steps {}..={} have to be a non-empty range above 0, plateaus allow equal levels",
+                profile.min_step, profile.max_step
+            )));
+        }
+        Ok(profile)
+    }
+
+    /// The fixed directions a report is checked in, a monotonic report in either of two.
+    fn directions(&self) -> &'static [Direction] {
+        match self.direction {
+            Direction::Monotonic => &[Direction::Ascending, Direction::Descending],
+            Direction::Ascending => &[Direction::Ascending],
+            Direction::Descending => &[Direction::Descending],
+            Direction::Free => &[Direction::Free],
+        }
+    }
+
+    /// Whether `next` can follow `level` in a safe report going in `direction`, which is not
+    /// `Monotonic`.
+    fn is_safe_step(&self, level: u64, next: u64, direction: Direction) -> bool {
+        if level == next {
+            return self.plateaus;
+        }
+        let is_right_way = match direction {
+            Direction::Ascending => level < next,
+            Direction::Descending => level > next,
+            Direction::Monotonic | Direction::Free => true,
+        };
+        is_right_way && (self.min_step..=self.max_step).contains(&level.abs_diff(next))
+    }
+
+    pub fn is_safe(&self, report: &[u64]) -> bool {
+        if report.len() < 2 {
+            return self.short_reports == ShortReports::Safe;
+        }
+        self.directions().iter().any(|&direction| {
+            report
+                .windows(2)
+                .all(|pair| self.is_safe_step(pair[0], pair[1], direction))
+        })
+    }
+
+    /// The minimum number of levels that have to be removed to make `report` safe, `None` if it
+    /// can't be made safe because short reports aren't. Takes expected linear time in the length
+    /// of long reports, unless the step range is wide.
+    pub fn min_removals(&self, report: &[u64]) -> Option<usize> {
+        let longest = self
+            .directions()
+            .iter()
+            .map(|&direction| self.longest_safe_subsequence(report, direction))
+            .max()
+            .unwrap_or(0);
+
+        let is_safe = longest >= 2 || self.short_reports == ShortReports::Safe;
+        is_safe.then(|| report.len() - longest)
+    }
+
+    /// Whether `report` is safe after removing at most `max_removals` levels.
+    pub fn is_safe_with(&self, report: &[u64], max_removals: usize) -> bool {
+        self.min_removals(report)
+            .is_some_and(|removals| removals <= max_removals)
+    }
+
+    /// The number of reports that are safe after removing at most `max_removals` levels from
+    /// each.
+    pub fn count_safe_with(&self, reports: &[Vec<u64>], max_removals: usize) -> u64 {
+        par::count(reports, |report| self.is_safe_with(report, max_removals))
+    }
+
+    /// The indices of the levels of a longest safe subsequence of `report`, `None` if there is
+    /// none because short reports aren't safe. Unlike [`Self::min_removals`] this is always
+    /// quadratic, it is meant for diagnostics.
+    pub fn longest_safe_levels(&self, report: &[u64]) -> Option<Vec<usize>> {
+        let mut longest = Vec::new();
+        for &direction in self.directions() {
+            let mut longest_ending_at = vec![1; report.len()];
+            let mut previous = vec![None; report.len()];
+            for idx in 0..report.len() {
+                for prev_idx in 0..idx {
+                    if self.is_safe_step(report[prev_idx], report[idx], direction)
+                        && longest_ending_at[prev_idx] + 1 > longest_ending_at[idx]
+                    {
+                        longest_ending_at[idx] = longest_ending_at[prev_idx] + 1;
+                        previous[idx] = Some(prev_idx);
+                    }
+                }
+            }
+
+            let Some(end) = (0..report.len()).max_by_key(|&idx| longest_ending_at[idx]) else {
+                continue;
+            };
+            if longest_ending_at[end] > longest.len() {
+                longest = std::iter::successors(Some(end), |&idx| previous[idx]).collect();
+                longest.reverse();
+            }
+        }
+
+        (longest.len() >= 2 || self.short_reports == ShortReports::Safe).then_some(longest)
+    }
+
+    /// The longest subsequence of `report` that is safe going in `direction`, which is not
+    /// `Monotonic`.
+    fn longest_safe_subsequence(&self, report: &[u64], direction: Direction) -> usize {
+        if report.len() > SMALL_REPORT && self.max_step - self.min_step < SMALL_STEP_RANGE {
+            return self.longest_safe_subsequence_hashed(report, direction);
+        }
+
+        let mut small = [0; SMALL_REPORT];
+        let mut large = Vec::new();
+        let longest_ending_at = if report.len() <= SMALL_REPORT {
+            &mut small[..report.len()]
+        } else {
+            large.resize(report.len(), 0);
+            &mut large[..]
+        };
+
+        for (idx, &level) in report.iter().enumerate() {
+            longest_ending_at[idx] = 1
+                + (0..idx)
+                    .filter(|&prev_idx| self.is_safe_step(report[prev_idx], level, direction))
+                    .map(|prev_idx| longest_ending_at[prev_idx])
+                    .max()
+                    .unwrap_or(0);
+        }
+        longest_ending_at.iter().copied().max().unwrap_or(0)
+    }
+
+    /// [`Self::longest_safe_subsequence`] with the longest subsequences ending in every level
+    /// value in a hash map, the previous level can only be one of the few values in step range.
+    fn longest_safe_subsequence_hashed(&self, report: &[u64], direction: Direction) -> usize {
+        let steps = self.min_step..=self.max_step;
+        let mut longest_ending_in: HashMap<u64, usize> = HashMap::with_capacity(report.len());
+        let mut longest = 0;
+        for &level in report {
+            let below = (direction != Direction::Descending).then(|| {
+                steps
+                    .clone()
+                    .filter_map(move |step| level.checked_sub(step))
+            });
+            let above = (direction != Direction::Ascending).then(|| {
+                steps
+                    .clone()
+                    .filter_map(move |step| level.checked_add(step))
+            });
+            let plateau = self.plateaus.then_some(level);
+            let previous = below
+                .into_iter()
+                .flatten()
+                .chain(above.into_iter().flatten())
+                .chain(plateau)
+                .filter_map(|previous| longest_ending_in.get(&previous).copied())
+                .max()
+                .unwrap_or(0);
+
+            let ending_here = longest_ending_in.entry(level).or_insert(0);
+            *ending_here = (*ending_here).max(previous + 1);
+            longest = longest.max(previous + 1);
+        }
+        longest
+    }
+}
@@ -0,0 +1,36 @@
+//! The reference the dampener is tested against, shared by the test files.
+
+use day2::RuleProfile;
+
+/// The fewest levels whose removal makes `report` safe, `None` if no removal does. Tries to
+/// remove no level, then every single level, every pair and so on, so it is exponential in the
+/// answer and only usable for short reports or ones with few bad levels.
+pub fn min_removals_by_search(rules: &RuleProfile, report: &[u64]) -> Option<usize> {
+    (0..=report.len())
+        .find(|&num_removed| is_safe_removing(rules, report, num_removed, 0, &mut Vec::new()))
+}
+
+/// Whether removing `num_removed` levels from `start` on in addition to `removed` makes `report`
+/// safe.
+fn is_safe_removing(
+    rules: &RuleProfile,
+    report: &[u64],
+    num_removed: usize,
+    start: usize,
+    removed: &mut Vec<usize>,
+) -> bool {
+    if num_removed == 0 {
+        let dampened: Vec<u64> = (0..report.len())
+            .filter(|idx| !removed.contains(idx))
+            .map(|idx| report[idx])
+            .collect();
+        return rules.is_safe(&dampened);
+    }
+
+    (start..report.len()).any(|idx| {
+        removed.push(idx);
+        let is_safe = is_safe_removing(rules, report, num_removed - 1, idx + 1, removed);
+        removed.pop();
+        is_safe
+    })
+}
@@ -0,0 +1,65 @@
+use day2::{count_safe_with, min_removals, solve_a, RuleProfile};
+use proptest::prelude::*;
+
+mod common;
+
+/// Reports with small steps, so many of them are safe or nearly safe.
+fn report() -> impl Strategy<Value = Vec<u64>> {
+    (10..20_u64, proptest::collection::vec(0..9_u64, 2..9)).prop_map(|(start, steps)| {
+        let mut level = start;
+        steps
+            .into_iter()
+            .map(|step| {
+                level = (level + step).saturating_sub(4);
+                level
+            })
+            .collect()
+    })
+}
+
+proptest! {
+    #[test]
+    fn matches_the_search(reports in proptest::collection::vec(report(), 0..50)) {
+        for report in &reports {
+            prop_assert_eq!(
+                min_removals(report),
+                common::min_removals_by_search(&RuleProfile::PUZZLE, report).unwrap(),
+                "{:?}",
+                report
+            );
+        }
+        prop_assert_eq!(count_safe_with(&reports, 0), solve_a(&reports));
+    }
+}
+
+#[test]
+fn example() {
+    let reports = [
+        vec![7, 6, 4, 2, 1],
+        vec![1, 2, 7, 8, 9],
+        vec![9, 7, 6, 2, 1],
+        vec![1, 3, 2, 4, 5],
+        vec![8, 6, 4, 4, 1],
+        vec![1, 3, 6, 7, 9],
+    ];
+    let removals: Vec<usize> = reports.iter().map(|report| min_removals(report)).collect();
+    assert_eq!(removals, [0, 2, 2, 1, 1, 0]);
+    assert_eq!(count_safe_with(&reports, 1), 4);
+    assert_eq!(count_safe_with(&reports, 2), 6);
+}
+
+#[test]
+fn long_reports() {
+    let ascending: Vec<u64> = (0..60).map(|idx| 100 + idx * 2 + idx % 2).collect();
+    let mut report = ascending.clone();
+    for (junk_idx, junk) in [(3, 5000), (20, 7), (21, 7), (40, 160)] {
+        report.insert(junk_idx, junk);
+    }
+    assert_eq!(min_removals(&ascending), 0);
+    assert_eq!(min_removals(&report), 4);
+
+    report.reverse();
+    assert_eq!(min_removals(&report), 4);
+    report.push(1);
+    assert_eq!(min_removals(&report), 5);
+}
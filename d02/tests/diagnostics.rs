@@ -0,0 +1,143 @@
+use day2::{
+    diagnose, first_problem, format_json, format_table, Direction, Problem, Reason, RuleProfile,
+    ShortReports,
+};
+use proptest::prelude::*;
+
+fn example() -> Vec<Vec<u64>> {
+    vec![
+        vec![7, 6, 4, 2, 1],
+        vec![1, 2, 7, 8, 9],
+        vec![9, 7, 6, 2, 1],
+        vec![1, 3, 2, 4, 5],
+        vec![8, 6, 4, 4, 1],
+        vec![1, 3, 6, 7, 9],
+    ]
+}
+
+fn problem(first: usize, reason: Reason) -> Option<Problem> {
+    Some(Problem {
+        levels: Some([first, first + 1]),
+        reason,
+    })
+}
+
+#[test]
+fn example_reports() {
+    let diagnoses = diagnose(&RuleProfile::PUZZLE, &example());
+    let problems: Vec<_> = diagnoses.iter().map(|d| d.problem.clone()).collect();
+    assert_eq!(
+        problems,
+        [
+            None,
+            problem(1, Reason::StepTooLarge),
+            problem(2, Reason::StepTooLarge),
+            problem(1, Reason::DirectionChange),
+            problem(2, Reason::ZeroStep),
+            None,
+        ]
+    );
+    let removed: Vec<_> = diagnoses.iter().map(|d| d.removed.clone()).collect();
+    assert_eq!(
+        removed,
+        [
+            Some(vec![]),
+            None,
+            None,
+            Some(vec![2]),
+            Some(vec![3]),
+            Some(vec![])
+        ]
+    );
+
+    let table = format_table(&diagnoses);
+    assert_eq!(table.lines().count(), 8);
+    assert!(table.contains("| 1 3 2 4 5 | no   | 1-2: direction change | removed 2 (2)"));
+
+    let json: serde_json::Value = serde_json::from_str(&format_json(&diagnoses)).unwrap();
+    assert_eq!(json[3]["problem"]["reason"], "direction_change");
+    assert_eq!(json[3]["removed"], serde_json::json!([2]));
+    assert_eq!(json[1]["removed"], serde_json::Value::Null);
+}
+
+#[test]
+fn other_rules() {
+    let rules = RuleProfile {
+        min_step: 2,
+        direction: Direction::Descending,
+        short_reports: ShortReports::Unsafe,
+        ..RuleProfile::PUZZLE
+    };
+    assert_eq!(
+        first_problem(&rules, &[9, 7, 6]),
+        problem(1, Reason::StepTooSmall)
+    );
+    assert_eq!(
+        first_problem(&rules, &[1, 3]),
+        problem(0, Reason::DirectionChange)
+    );
+    assert_eq!(
+        first_problem(&rules, &[4]),
+        Some(Problem {
+            levels: None,
+            reason: Reason::TooShort
+        })
+    );
+    assert_eq!(diagnose(&rules, &[vec![4]])[0].removed, None);
+}
+
+/// Reports of up to eight levels with steps of up to five in either direction.
+fn report() -> impl Strategy<Value = Vec<u64>> {
+    proptest::collection::vec(0..11_u64, 0..9).prop_map(|steps| {
+        let mut level = 50;
+        steps
+            .into_iter()
+            .map(|step| {
+                level = level + step - 5;
+                level
+            })
+            .collect()
+    })
+}
+
+proptest! {
+    /// The diagnoses agree with the counts, and removing the levels makes the reports safe.
+    #[test]
+    fn matches_the_counts(reports in proptest::collection::vec(report(), 0..50)) {
+        for rules in [
+            RuleProfile::PUZZLE,
+            RuleProfile {
+                plateaus: true,
+                direction: Direction::Free,
+                short_reports: ShortReports::Unsafe,
+                dampener: 2,
+                ..RuleProfile::PUZZLE
+            },
+        ] {
+            let diagnoses = diagnose(&rules, &reports);
+            let num_safe = diagnoses.iter().filter(|d| d.safe).count() as u64;
+            let num_dampened = diagnoses.iter().filter(|d| d.removed.is_some()).count() as u64;
+            prop_assert_eq!(num_safe, rules.count_safe_with(&reports, 0));
+            prop_assert_eq!(
+                num_dampened,
+                rules.count_safe_with(&reports, rules.dampener)
+            );
+
+            for (diagnosis, report) in diagnoses.iter().zip(&reports) {
+                prop_assert_eq!(diagnosis.safe, rules.is_safe(report));
+                if let Some(removed) = &diagnosis.removed {
+                    let dampened: Vec<u64> = (0..report.len())
+                        .filter(|idx| !removed.contains(idx))
+                        .map(|idx| report[idx])
+                        .collect();
+                    prop_assert!(
+                        rules.is_safe(&dampened),
+                        "{:?} without {:?}",
+                        report,
+                        removed
+                    );
+                }
+            }
+        }
+    }
+}
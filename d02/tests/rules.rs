@@ -0,0 +1,153 @@
+use std::ops::Range;
+
+use day2::{Direction, RuleProfile, ShortReports};
+use proptest::{prelude::*, sample::Index};
+
+mod common;
+
+/// Reports with `len` levels and steps of up to `max_step` in either direction.
+fn report(len: Range<usize>, max_step: u64) -> impl Strategy<Value = Vec<u64>> {
+    (
+        100..200_u64,
+        proptest::collection::vec(0..=2 * max_step, len),
+    )
+        .prop_map(move |(start, steps)| {
+            let mut level = start;
+            steps
+                .into_iter()
+                .map(|step| {
+                    level = (level + step).saturating_sub(max_step);
+                    level
+                })
+                .collect()
+        })
+}
+
+/// A profile with a report whose steps are sometimes too large for it.
+fn profile_and_report(len: Range<usize>) -> impl Strategy<Value = (RuleProfile, Vec<u64>)> {
+    proptest::sample::select(profiles())
+        .prop_flat_map(move |rules| (Just(rules), report(len.clone(), rules.max_step + 1)))
+}
+
+/// A report with `len` levels that is safe under `rules`, with up to two other levels inserted
+/// anywhere. So at most two levels have to be removed and the search stays fast.
+fn nearly_safe_report(rules: RuleProfile, len: Range<usize>) -> impl Strategy<Value = Vec<u64>> {
+    let step = if rules.plateaus {
+        prop_oneof![Just(0), rules.min_step..=rules.max_step].boxed()
+    } else {
+        (rules.min_step..=rules.max_step).boxed()
+    };
+    let ascending = match rules.direction {
+        Direction::Ascending => Just(true).boxed(),
+        Direction::Descending => Just(false).boxed(),
+        Direction::Monotonic | Direction::Free => any::<bool>().boxed(),
+    };
+    let steps = proptest::collection::vec((step, any::<bool>()), len);
+    let junk = proptest::collection::vec((any::<Index>(), 0..20_000_u64), 0..=2);
+
+    (ascending, steps, junk).prop_map(move |(ascending, steps, junk)| {
+        let mut level = 10_000;
+        let mut report: Vec<u64> = steps
+            .into_iter()
+            .map(|(step, flip)| {
+                // only free reports may change their direction
+                let up = if rules.direction == Direction::Free {
+                    flip
+                } else {
+                    ascending
+                };
+                level = if up { level + step } else { level - step };
+                level
+            })
+            .collect();
+        for (idx, junk_level) in junk {
+            report.insert(idx.index(report.len() + 1), junk_level);
+        }
+        report
+    })
+}
+
+fn profiles() -> Vec<RuleProfile> {
+    let mut profiles = Vec::new();
+    for (min_step, max_step) in [(1, 3), (2, 5), (1, 12)] {
+        for plateaus in [false, true] {
+            for direction in [
+                Direction::Monotonic,
+                Direction::Ascending,
+                Direction::Descending,
+                Direction::Free,
+            ] {
+                for short_reports in [ShortReports::Safe, ShortReports::Unsafe] {
+                    profiles.push(RuleProfile {
+                        min_step,
+                        max_step,
+                        plateaus,
+                        direction,
+                        short_reports,
+                        dampener: 1,
+                    });
+                }
+            }
+        }
+    }
+    profiles
+}
+
+proptest! {
+    #[test]
+    fn short_reports_match_the_search((rules, report) in profile_and_report(0..9)) {
+        prop_assert_eq!(
+            rules.min_removals(&report),
+            common::min_removals_by_search(&rules, &report)
+        );
+    }
+
+    #[test]
+    fn long_reports_match_the_search(
+        (rules, report) in proptest::sample::select(profiles())
+            .prop_flat_map(|rules| (Just(rules), nearly_safe_report(rules, 30..80))),
+    ) {
+        let removals = rules.min_removals(&report);
+        prop_assert!(removals.is_some_and(|removals| removals <= 2));
+        prop_assert_eq!(removals, common::min_removals_by_search(&rules, &report));
+    }
+}
+
+#[test]
+fn puzzle_rules() {
+    let rules = RuleProfile::PUZZLE;
+    assert!(rules.is_safe(&[7, 6, 4, 2, 1]));
+    assert!(!rules.is_safe(&[8, 6, 4, 4, 1]));
+    assert!(!rules.is_safe(&[1, 2, 1]));
+    assert!(rules.is_safe(&[5]));
+    assert!(rules.is_safe(&[]));
+    assert_eq!(RuleProfile::from_toml("").unwrap(), rules);
+}
+
+#[test]
+fn profile_from_toml() {
+    let rules = RuleProfile::from_toml(
+        "max_step = 5\nplateaus = true\ndirection = \"free\"\nshort_reports = \"unsafe\"\n",
+    )
+    .unwrap();
+    assert_eq!(
+        rules,
+        RuleProfile {
+            max_step: 5,
+            plateaus: true,
+            direction: Direction::Free,
+            short_reports: ShortReports::Unsafe,
+            ..RuleProfile::PUZZLE
+        }
+    );
+    assert!(rules.is_safe(&[1, 6, 6, 2]));
+    assert!(!rules.is_safe(&[1, 7]));
+    assert!(!rules.is_safe(&[1]));
+    assert_eq!(rules.min_removals(&[1]), None);
+    assert_eq!(rules.min_removals(&[1, 20, 3]), Some(1));
+
+    assert!(RuleProfile::from_toml("max_steps = 5").is_err());
+    assert!(RuleProfile::from_toml("direction = \"sideways\"").is_err());
+    assert!(RuleProfile::from_toml("min_step = 0").is_err());
+    assert!(RuleProfile::from_toml("min_step = 4").is_err());
+}
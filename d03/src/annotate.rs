@@ -0,0 +1,195 @@
+//! An annotated view of the memory for debugging: every instruction with the state of the part 2
+//! scan at that point, as a listing or as the memory with the disabled regions dimmed, for a
+//! terminal (ANSI) or a browser (HTML).
+
+use std::ops::Range;
+
+use crate::{
+    eval::Machine,
+    instruction::{Instruction, Spanned},
+};
+
+/// An instruction with the state of the part 2 scan when it runs.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Annotation {
+    pub spanned: Spanned,
+    /// Whether the `mul`s were enabled right before the instruction.
+    pub enabled: bool,
+    /// The product of a `mul`, also of a disabled one.
+    pub product: Option<u64>,
+    /// The sum after the instruction.
+    pub running_sum: u64,
+}
+
+/// Runs `program` like part 2 and records the state at every instruction.
+pub fn annotate(program: &[Spanned]) -> Vec<Annotation> {
+    let mut machine = Machine::new(true);
+    program
+        .iter()
+        .map(|spanned| {
+            let enabled = machine.enabled;
+            machine.execute(&spanned.instruction);
+            let product = match spanned.instruction {
+                Instruction::Mul(lhs, rhs) => Some(lhs * rhs),
+                Instruction::Do | Instruction::Dont => None,
+            };
+            Annotation {
+                spanned: spanned.clone(),
+                enabled,
+                product,
+                running_sum: machine.sum,
+            }
+        })
+        .collect()
+}
+
+/// One line per instruction: the byte offset, the instruction, whether it was enabled, the
+/// product and the running sum.
+pub fn format_listing(annotations: &[Annotation]) -> String {
+    let offset_width = annotations
+        .last()
+        .map_or(0, |last| last.spanned.span.start.to_string().len())
+        .max("offset".len());
+    let product_width = annotations
+        .iter()
+        .filter_map(|annotation| annotation.product)
+        .map(|product| product.to_string().len())
+        .max()
+        .unwrap_or(0)
+        .max("product".len());
+
+    let mut listing = format!(
+        "{:>offset_width$}  {:<12}  {:<8}  {:>product_width$}  {}\n",
+        "offset", "instruction", "state", "product", "sum"
+    );
+    for annotation in annotations {
+        let product = annotation
+            .product
+            .map_or("-".to_string(), |product| product.to_string());
+        listing += &format!(
+            "{:>offset_width$}  {:<12}  {:<8}  {:>product_width$}  {}\n",
+            annotation.spanned.span.start,
+            annotation.spanned.instruction.to_string(),
+            if annotation.enabled {
+                "enabled"
+            } else {
+                "disabled"
+            },
+            product,
+            annotation.running_sum,
+        );
+    }
+    listing
+}
+
+/// How a piece of the memory is rendered.
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Style {
+    Corruption,
+    /// The corruption and the `mul`s between a `don't()` and the next `do()`.
+    Disabled,
+    Counted,
+    Conditional,
+}
+
+/// Splits `memory` into pieces with their style, neighboring pieces have different styles.
+fn pieces<'a>(memory: &'a [u8], annotations: &[Annotation]) -> Vec<(Style, &'a [u8])> {
+    let mut ranges: Vec<(Style, Range<usize>)> = Vec::new();
+    let mut push = |style, range: Range<usize>| match ranges.last_mut() {
+        Some((last_style, last_range)) if *last_style == style => last_range.end = range.end,
+        _ if range.is_empty() => {}
+        _ => ranges.push((style, range)),
+    };
+
+    let mut enabled = true;
+    let mut idx = 0;
+    for annotation in annotations {
+        let span = annotation.spanned.span.clone();
+        let corruption = if enabled {
+            Style::Corruption
+        } else {
+            Style::Disabled
+        };
+        push(corruption, idx..span.start);
+
+        let style = match annotation.spanned.instruction {
+            Instruction::Mul(..) if annotation.enabled => Style::Counted,
+            Instruction::Mul(..) => Style::Disabled,
+            Instruction::Do => {
+                enabled = true;
+                Style::Conditional
+            }
+            Instruction::Dont => {
+                enabled = false;
+                Style::Conditional
+            }
+        };
+        idx = span.end;
+        push(style, span);
+    }
+
+    let corruption = if enabled {
+        Style::Corruption
+    } else {
+        Style::Disabled
+    };
+    push(corruption, idx..memory.len());
+
+    ranges
+        .into_iter()
+        .map(|(style, range)| (style, &memory[range]))
+        .collect()
+}
+
+/// The memory with ANSI colors: counted `mul`s bold green, conditionals cyan and everything in
+/// the disabled regions dimmed.
+pub fn render_ansi(memory: &[u8], annotations: &[Annotation]) -> String {
+    let mut rendered = String::new();
+    for (style, piece) in pieces(memory, annotations) {
+        let piece = String::from_utf8_lossy(piece);
+        let escape = match style {
+            Style::Corruption => {
+                rendered += &piece;
+                continue;
+            }
+            Style::Disabled => "\x1b[2m",
+            Style::Counted => "\x1b[1;32m",
+            Style::Conditional => "\x1b[36m",
+        };
+        rendered += &format!("{escape}{piece}\x1b[0m");
+    }
+    rendered
+}
+
+fn escape_html(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
+
+/// The memory as a standalone HTML page, styled like [`render_ansi`].
+pub fn render_html(memory: &[u8], annotations: &[Annotation]) -> String {
+    let mut html = String::from(concat!(
+        "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<style>\n",
+        "pre { white-space: pre-wrap; word-break: break-all; }\n",
+        ".disabled { opacity: 0.4; }\n",
+        ".counted { color: green; font-weight: bold; }\n",
+        ".conditional { color: darkcyan; }\n",
+        "</style>\n</head>\n<body>\n<pre>",
+    ));
+    for (style, piece) in pieces(memory, annotations) {
+        let piece = escape_html(&String::from_utf8_lossy(piece));
+        let class = match style {
+            Style::Corruption => {
+                html += &piece;
+                continue;
+            }
+            Style::Disabled => "disabled",
+            Style::Counted => "counted",
+            Style::Conditional => "conditional",
+        };
+        html += &format!("<span class=\"{class}\">{piece}</span>");
+    }
+    html += "</pre>\n</body>\n</html>\n";
+    html
+}
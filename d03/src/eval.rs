@@ -0,0 +1,50 @@
+//! Runs the instructions: `mul` adds its product to the sum, `don't()` disables the `mul`s until
+//! the next `do()`. Part 1 ignores the conditionals.
+
+use crate::instruction::{Instruction, Spanned};
+
+pub struct Machine {
+    /// Whether `do()` and `don't()` have an effect.
+    conditionals: bool,
+    pub enabled: bool,
+    pub sum: u64,
+}
+
+impl Machine {
+    pub fn new(conditionals: bool) -> Self {
+        Self {
+            conditionals,
+            enabled: true,
+            sum: 0,
+        }
+    }
+
+    /// Executes `instruction`, returns the product it added to the sum, if any.
+    pub fn execute(&mut self, instruction: &Instruction) -> Option<u64> {
+        match *instruction {
+            Instruction::Mul(lhs, rhs) if self.enabled => {
+                let product = lhs * rhs;
+                self.sum += product;
+                Some(product)
+            }
+            Instruction::Mul(..) => None,
+            Instruction::Do => {
+                self.enabled = true;
+                None
+            }
+            Instruction::Dont => {
+                self.enabled = !self.conditionals;
+                None
+            }
+        }
+    }
+}
+
+/// The sum of the enabled products of `program`.
+pub fn evaluate(program: &[Spanned], conditionals: bool) -> u64 {
+    let mut machine = Machine::new(conditionals);
+    for spanned in program {
+        machine.execute(&spanned.instruction);
+    }
+    machine.sum
+}
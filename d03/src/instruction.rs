@@ -0,0 +1,61 @@
+//! The instructions of the corrupted memory and the table the lexer recognizes them by. A new
+//! instruction needs a variant, an entry in [`INSTRUCTIONS`], an arm in
+//! [`Machine::execute`](crate::Machine::execute) and one in the `Display` impl.
+
+use std::{fmt::Display, ops::Range};
+
+/// Instructions take at most this many operands.
+pub const MAX_OPERANDS: usize = 2;
+
+/// Operands have one to this many digits.
+pub const MAX_DIGITS: usize = 3;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Instruction {
+    Mul(u64, u64),
+    Do,
+    Dont,
+}
+
+impl Display for Instruction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Instruction::Mul(lhs, rhs) => write!(f, "mul({lhs},{rhs})"),
+            Instruction::Do => write!(f, "do()"),
+            Instruction::Dont => write!(f, "don't()"),
+        }
+    }
+}
+
+/// An instruction with the byte range it was found at in the memory.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Spanned {
+    pub instruction: Instruction,
+    pub span: Range<usize>,
+}
+
+/// How an instruction is written: `name(` followed by `arity` comma separated operands and `)`.
+pub struct InstructionSpec {
+    pub name: &'static str,
+    pub arity: usize,
+    /// Builds the instruction from its `arity` operands.
+    pub build: fn(&[u64]) -> Instruction,
+}
+
+pub const INSTRUCTIONS: &[InstructionSpec] = &[
+    InstructionSpec {
+        name: "mul",
+        arity: 2,
+        build: |operands| Instruction::Mul(operands[0], operands[1]),
+    },
+    InstructionSpec {
+        name: "do",
+        arity: 0,
+        build: |_| Instruction::Do,
+    },
+    InstructionSpec {
+        name: "don't",
+        arity: 0,
+        build: |_| Instruction::Dont,
+    },
+];
@@ -0,0 +1,110 @@
+//! Finds the instructions of [`INSTRUCTIONS`] in the corrupted memory. The grammar is strict:
+//! the name, `(`, the operands of one to three digits separated by `,` and `)`, without any
+//! whitespace. Everything else is corruption and skipped one byte at a time, so an instruction
+//! can start inside a broken one, like the `mul(2,3)` in `mul(mul(2,3)`.
+
+use crate::instruction::{
+    Instruction, InstructionSpec, Spanned, INSTRUCTIONS, MAX_DIGITS, MAX_OPERANDS,
+};
+
+/// What [`match_at`] found at the start of the bytes it got.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum Match {
+    /// An instruction and its length.
+    Found(Instruction, usize),
+    /// The bytes end in the middle of what could still become an instruction.
+    Incomplete,
+    NoMatch,
+}
+
+/// Checks that `rest` continues with `expected` at `idx`, an error is [`Match::NoMatch`] or
+/// [`Match::Incomplete`].
+fn expect(rest: &[u8], idx: usize, expected: &[u8]) -> Result<(), Match> {
+    let available = &rest[idx..];
+    if available.starts_with(expected) {
+        Ok(())
+    } else if expected.starts_with(available) {
+        Err(Match::Incomplete)
+    } else {
+        Err(Match::NoMatch)
+    }
+}
+
+/// Reads an operand at `idx`, returns it with the number of digits.
+fn operand(rest: &[u8], idx: usize) -> Result<(u64, usize), Match> {
+    let num_digits = rest[idx..]
+        .iter()
+        .take_while(|byte| byte.is_ascii_digit())
+        .count();
+    if num_digits > MAX_DIGITS {
+        return Err(Match::NoMatch);
+    }
+    if idx + num_digits == rest.len() {
+        // more digits or the rest of the instruction may follow
+        return Err(Match::Incomplete);
+    }
+    if num_digits == 0 {
+        return Err(Match::NoMatch);
+    }
+
+    let value = rest[idx..idx + num_digits]
+        .iter()
+        .fold(0, |value, digit| value * 10 + (digit - b'0') as u64);
+    Ok((value, num_digits))
+}
+
+/// Matches the instruction of `spec` at the start of `rest`, returns it with its length.
+fn match_spec(spec: &InstructionSpec, rest: &[u8]) -> Result<(Instruction, usize), Match> {
+    expect(rest, 0, spec.name.as_bytes())?;
+    let mut len = spec.name.len();
+    expect(rest, len, b"(")?;
+    len += 1;
+
+    let mut operands = [0; MAX_OPERANDS];
+    for (operand_idx, slot) in operands[..spec.arity].iter_mut().enumerate() {
+        if operand_idx > 0 {
+            expect(rest, len, b",")?;
+            len += 1;
+        }
+        let (value, num_digits) = operand(rest, len)?;
+        *slot = value;
+        len += num_digits;
+    }
+
+    expect(rest, len, b")")?;
+    Ok(((spec.build)(&operands[..spec.arity]), len + 1))
+}
+
+/// Matches any instruction at the start of `rest`. The names can't be ambiguous because they
+/// are followed by `(`, so at most one instruction is found.
+pub fn match_at(rest: &[u8]) -> Match {
+    let mut result = Match::NoMatch;
+    for spec in INSTRUCTIONS {
+        match match_spec(spec, rest) {
+            Ok((instruction, len)) => return Match::Found(instruction, len),
+            Err(Match::Incomplete) => result = Match::Incomplete,
+            Err(_) => {}
+        }
+    }
+    result
+}
+
+/// All instructions in `memory` in order.
+pub fn lex(memory: &[u8]) -> Vec<Spanned> {
+    let mut instructions = Vec::new();
+    let mut idx = 0;
+    while idx < memory.len() {
+        match match_at(&memory[idx..]) {
+            Match::Found(instruction, len) => {
+                instructions.push(Spanned {
+                    instruction,
+                    span: idx..idx + len,
+                });
+                idx += len;
+            }
+            // nothing follows the end of the memory
+            Match::Incomplete | Match::NoMatch => idx += 1,
+        }
+    }
+    instructions
+}
@@ -0,0 +1,42 @@
+use runner::{ParseError, Solution};
+
+mod annotate;
+mod eval;
+mod instruction;
+mod lexer;
+mod stream;
+
+pub use annotate::{annotate, format_listing, render_ansi, render_html, Annotation};
+pub use eval::{evaluate, Machine};
+pub use instruction::{Instruction, InstructionSpec, Spanned, INSTRUCTIONS};
+pub use lexer::{lex, match_at, Match};
+pub use stream::{sum_reader, Scanner, Sums};
+
+pub fn solve_a(program: &[Spanned]) -> u64 {
+    evaluate(program, false)
+}
+
+pub fn solve_b(program: &[Spanned]) -> u64 {
+    evaluate(program, true)
+}
+
+pub struct Day3;
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    /// The instructions found in the memory, everything else is corruption.
+    type Input = Vec<Spanned>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Ok(lex(input.as_bytes()))
+    }
+
+    fn part1(program: &Self::Input) -> String {
+        solve_a(program).to_string()
+    }
+
+    fn part2(program: &Self::Input) -> Option<String> {
+        Some(solve_b(program).to_string())
+    }
+}
@@ -1,61 +1,78 @@
-use regex::Regex;
-
-const MUL_PATTERN: &str = r"mul\((\d+),(\d+)\)";
-const START_MUL_PATTERN: &str = r"^mul\((\d+),(\d+)\)";
-
-fn solve_a(input: &str) -> u64 {
-    let re = Regex::new(MUL_PATTERN).unwrap();
-
-    re
-        .captures_iter(input)
-        .map(|cap| {
-            let n: u64 = cap[1].parse().unwrap();
-            let m: u64 = cap[2].parse().unwrap();
-            n * m
-        })
-        .sum()
-}
+use std::{fs::File, io, path::Path};
 
-fn solve_b(input: &str) -> u64 {
-    let mut enabled = true;
-    let mut idx = 0;
+use day3::Day3;
+use runner::exit_with;
 
-    let re = Regex::new(START_MUL_PATTERN).unwrap();
+const USAGE: &str = "usage: day3 [INPUT] [--stream | --annotate list|ansi|html]";
 
-    let mut sum = 0;
+/// Memory dumps are streamed in blocks of this size.
+const BLOCK_SIZE: usize = 1 << 16;
 
-    while idx < input.len() {
-        if input[idx..].starts_with("don't") {
-            enabled = false;
-            idx += "don't".len();
-            continue;   
-        }
-        if input[idx..].starts_with("do") {
-            enabled = true;
-            idx += "do".len();
-            continue;
-        }
-        if enabled && input[idx..].starts_with("mul(") {
-            if let Some(cap) = re.captures(&input[idx..]) {
-                let n: u64 = cap[1].parse().unwrap();
-                let m: u64 = cap[2].parse().unwrap();
-                sum += n * m;
-                idx += cap.len();
-                continue;
-            }
-        }
-        idx += 1;
-    }
+/// Scans the input of `selection` block by block instead of reading it into a string first, for
+/// memory dumps larger than the RAM. Encrypted inputs are decrypted into memory first.
+fn run_streamed(day_dir: &Path, selection: &str) {
+    let selected = runner::load_config(day_dir, selection);
+    let answers = if selected.file == Path::new("-") {
+        day3::sum_reader(io::stdin().lock(), BLOCK_SIZE)
+    } else if runner::crypt::is_encrypted_path(&selected.file) || !selected.file.exists() {
+        // only the encrypted input is checked out, which is small enough to be decrypted in memory
+        runner::read_input(&selected.file)
+            .and_then(|input| day3::sum_reader(input.as_bytes(), BLOCK_SIZE))
+    } else {
+        File::open(&selected.file).and_then(|file| day3::sum_reader(file, BLOCK_SIZE))
+    };
+    let (part1, part2) = answers.unwrap_or_else(|err| {
+        exit_with(format!(
+            "Cannot read input {}: {err}",
+            selected.file.display()
+        ))
+    });
 
-    sum
+    println!("Task1: {part1}");
+    println!("Task2: {part2}");
+    runner::check_answers(&selected, &part1.to_string(), Some(&part2.to_string()));
+}
+
+/// Prints the input of `selection` annotated in `format`, see `day3::annotate`.
+fn run_annotated(day_dir: &Path, selection: &str, format: &str) {
+    let (_, memory) = runner::load_selection(day_dir, selection);
+
+    let annotations = day3::annotate(&day3::lex(memory.as_bytes()));
+    match format {
+        "list" => print!("{}", day3::format_listing(&annotations)),
+        "ansi" => println!("{}", day3::render_ansi(memory.as_bytes(), &annotations)),
+        "html" => print!("{}", day3::render_html(memory.as_bytes(), &annotations)),
+        _ => exit_with(format!("unknown format {format:?}, {USAGE}")),
+    }
 }
 
 fn main() {
-    let input = std::fs::read_to_string("input.txt").unwrap();
-    
-    let output_a = solve_a(&input);
-    let output_b = solve_b(&input);
+    let day_dir = env!("CARGO_MANIFEST_DIR");
+
+    let mut selection = "input".to_string();
+    let mut streamed = false;
+    let mut annotate_format = None;
+    let mut args = std::env::args().skip(1);
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--stream" => streamed = true,
+            "--annotate" => annotate_format = Some(args.next().unwrap_or_else(|| exit_with(USAGE))),
+            _ if arg.starts_with("--") => exit_with(USAGE),
+            _ => selection = arg,
+        }
+    }
 
-    println!("Task1: {output_a}");
-    println!("Task2: {output_b}");
+    if streamed && annotate_format.is_some() {
+        exit_with(format!(
+            "--stream and --annotate cannot be combined, {USAGE}"
+        ));
+    }
+
+    if let Some(format) = annotate_format {
+        run_annotated(Path::new(day_dir), &selection, &format);
+    } else if streamed {
+        run_streamed(Path::new(day_dir), &selection);
+    } else {
+        runner::run_selection::<Day3>(day_dir, &selection);
+    }
 }
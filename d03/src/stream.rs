@@ -0,0 +1,109 @@
+//! Scanning memory dumps that don't fit into memory, chunk by chunk. An instruction can be split
+//! between two chunks, so the scanner keeps the bytes from the first position that could still
+//! become an instruction and scans them again with the next chunk. That tail is never longer
+//! than an instruction.
+
+use std::io::{self, Read};
+
+use crate::{
+    eval::Machine,
+    instruction::Spanned,
+    lexer::{match_at, Match},
+};
+
+/// Finds the instructions in chunks of memory, with the same results as
+/// [`lex`](crate::lex) of the whole memory.
+#[derive(Default)]
+pub struct Scanner {
+    /// The unscanned tail of the chunks so far.
+    pending: Vec<u8>,
+    /// The offset of `pending` in the memory.
+    offset: usize,
+}
+
+impl Scanner {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Scans the next chunk and hands every instruction found to `emit`.
+    pub fn feed(&mut self, chunk: &[u8], emit: impl FnMut(Spanned)) {
+        self.pending.extend_from_slice(chunk);
+        self.scan(false, emit);
+    }
+
+    /// Scans what is left at the end of the memory.
+    pub fn finish(mut self, emit: impl FnMut(Spanned)) {
+        self.scan(true, emit);
+    }
+
+    fn scan(&mut self, is_last: bool, mut emit: impl FnMut(Spanned)) {
+        let mut idx = 0;
+        while idx < self.pending.len() {
+            match match_at(&self.pending[idx..]) {
+                Match::Found(instruction, len) => {
+                    let start = self.offset + idx;
+                    emit(Spanned {
+                        instruction,
+                        span: start..start + len,
+                    });
+                    idx += len;
+                }
+                Match::Incomplete if !is_last => break,
+                Match::Incomplete | Match::NoMatch => idx += 1,
+            }
+        }
+
+        self.pending.drain(..idx);
+        self.offset += idx;
+    }
+}
+
+/// The sums of both parts, with the state of the conditionals carried over between chunks.
+pub struct Sums {
+    part1: Machine,
+    part2: Machine,
+}
+
+impl Default for Sums {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Sums {
+    pub fn new() -> Self {
+        Self {
+            part1: Machine::new(false),
+            part2: Machine::new(true),
+        }
+    }
+
+    pub fn execute(&mut self, spanned: &Spanned) {
+        self.part1.execute(&spanned.instruction);
+        self.part2.execute(&spanned.instruction);
+    }
+
+    /// The answers of part 1 and 2.
+    pub fn answers(&self) -> (u64, u64) {
+        (self.part1.sum, self.part2.sum)
+    }
+}
+
+/// Streams the memory from `reader` in blocks of `block_size` bytes and returns the answers of
+/// both parts, the dump never has to fit into memory as a whole.
+pub fn sum_reader(mut reader: impl Read, block_size: usize) -> io::Result<(u64, u64)> {
+    let mut scanner = Scanner::new();
+    let mut sums = Sums::new();
+    let mut block = vec![0; block_size];
+    loop {
+        match reader.read(&mut block) {
+            Ok(0) => break,
+            Ok(len) => scanner.feed(&block[..len], |spanned| sums.execute(&spanned)),
+            Err(err) if err.kind() == io::ErrorKind::Interrupted => continue,
+            Err(err) => return Err(err),
+        }
+    }
+    scanner.finish(|spanned| sums.execute(&spanned));
+    Ok(sums.answers())
+}
@@ -0,0 +1,77 @@
+use day3::{annotate, format_listing, lex, render_ansi, render_html, solve_b, Instruction};
+
+const MEMORY: &[u8] =
+    b"xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))<&>";
+
+#[test]
+fn annotations() {
+    let program = lex(MEMORY);
+    let annotations = annotate(&program);
+
+    let summary: Vec<_> = annotations
+        .iter()
+        .map(|annotation| {
+            (
+                annotation.spanned.span.start,
+                annotation.enabled,
+                annotation.product,
+                annotation.running_sum,
+            )
+        })
+        .collect();
+    assert_eq!(
+        summary,
+        [
+            (1, true, Some(8), 8),
+            (20, true, None, 8),
+            (28, false, Some(25), 8),
+            (48, false, Some(88), 8),
+            (59, false, None, 8),
+            (64, true, Some(40), 48),
+        ]
+    );
+    assert_eq!(annotations.last().unwrap().running_sum, solve_b(&program));
+    assert_eq!(annotations[1].spanned.instruction, Instruction::Dont);
+
+    let listing = format_listing(&annotations);
+    assert_eq!(listing.lines().count(), 7);
+    assert_eq!(
+        listing.lines().next().unwrap(),
+        "offset  instruction   state     product  sum"
+    );
+    assert_eq!(
+        listing.lines().nth(3).unwrap(),
+        "    28  mul(5,5)      disabled       25  8"
+    );
+}
+
+/// Removes the escape sequences, `ESC [ ... m`.
+fn strip_ansi(text: &str) -> String {
+    let mut stripped = String::new();
+    let mut chars = text.chars();
+    while let Some(c) = chars.next() {
+        if c == '\x1b' {
+            chars.by_ref().find(|&c| c == 'm');
+        } else {
+            stripped.push(c);
+        }
+    }
+    stripped
+}
+
+#[test]
+fn renderings() {
+    let annotations = annotate(&lex(MEMORY));
+    let memory = std::str::from_utf8(MEMORY).unwrap();
+
+    let ansi = render_ansi(MEMORY, &annotations);
+    assert_eq!(strip_ansi(&ansi), memory);
+    assert!(ansi.contains("\x1b[1;32mmul(8,5)\x1b[0m"));
+    assert!(ansi.contains("\x1b[2m_mul(5,5)+mul(32,64](mul(11,8)un\x1b[0m"));
+
+    let html = render_html(MEMORY, &annotations);
+    assert!(html.contains(
+        "<span class=\"disabled\">_mul(5,5)+mul(32,64](mul(11,8)un</span><span class=\"conditional\">do()</span>"
+    ));
+    assert!(html.contains("mul(8,5)</span>)&lt;&amp;&gt;</pre>"));
+}
@@ -0,0 +1,66 @@
+use std::{
+    path::{Path, PathBuf},
+    process::Command,
+};
+
+use runner::crypt;
+
+const MEMORY: &str = "xmul(2,4)&mul[3,7]!^don't()_mul(5,5)+mul(32,64](mul(11,8)undo()?mul(8,5))\n";
+const KEY: &str = "correct horse battery staple";
+
+fn day3(args: &[&str], input: &Path) -> (bool, String, String) {
+    let output = Command::new(env!("CARGO_BIN_EXE_day3"))
+        .args(args)
+        .arg(input)
+        .env(crypt::KEY_VAR, KEY)
+        .output()
+        .unwrap();
+    (
+        output.status.success(),
+        String::from_utf8(output.stdout).unwrap(),
+        String::from_utf8(output.stderr).unwrap(),
+    )
+}
+
+/// Like the default input after a fresh checkout, only `input.txt.enc` exists.
+fn encrypted_input(dir: &Path) -> PathBuf {
+    std::env::set_var(crypt::KEY_VAR, KEY);
+    let encrypted = crypt::encrypt(MEMORY.as_bytes()).unwrap();
+    let input = dir.join("input.txt");
+    std::fs::write(crypt::encrypted_path(&input), encrypted).unwrap();
+    input
+}
+
+#[test]
+fn streams_the_encrypted_input() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = encrypted_input(dir.path());
+
+    let (success, stdout, stderr) = day3(&["--stream"], &input);
+    assert!(success, "{stderr}");
+    assert_eq!(stdout, "Task1: 161\nTask2: 48\n");
+
+    let (success, stdout, stderr) = day3(&["--stream"], &crypt::encrypted_path(&input));
+    assert!(success, "{stderr}");
+    assert_eq!(stdout, "Task1: 161\nTask2: 48\n");
+}
+
+#[test]
+fn streams_the_plain_input() {
+    let dir = tempfile::tempdir().unwrap();
+    let input = dir.path().join("input.txt");
+    std::fs::write(&input, MEMORY).unwrap();
+
+    let (success, stdout, stderr) = day3(&["--stream"], &input);
+    assert!(success, "{stderr}");
+    assert_eq!(stdout, "Task1: 161\nTask2: 48\n");
+}
+
+#[test]
+fn missing_input() {
+    let dir = tempfile::tempdir().unwrap();
+
+    let (success, _, stderr) = day3(&["--stream"], &dir.path().join("input.txt"));
+    assert!(!success);
+    assert!(stderr.starts_with("Cannot read input"), "{stderr}");
+}
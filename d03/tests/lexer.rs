@@ -0,0 +1,58 @@
+use day3::{evaluate, lex, Instruction, Spanned};
+
+fn instructions(memory: &str) -> Vec<Instruction> {
+    lex(memory.as_bytes())
+        .into_iter()
+        .map(|spanned| spanned.instruction)
+        .collect()
+}
+
+#[test]
+fn strict_grammar() {
+    assert_eq!(
+        instructions("mul(1,22)mul(333,4)"),
+        [Instruction::Mul(1, 22), Instruction::Mul(333, 4)]
+    );
+    assert_eq!(
+        instructions("mul(1234,5)mul(,5)mul(1,)mul(1 ,2)mul( 1,2)mul(1,2,3)"),
+        []
+    );
+    assert_eq!(instructions("MUL(1,2)mul[1,2]mul(1,2"), []);
+    assert_eq!(instructions("do don't do( don't)"), []);
+    assert_eq!(
+        instructions("do()don't()undo()"),
+        [Instruction::Do, Instruction::Dont, Instruction::Do]
+    );
+    assert_eq!(instructions("do(1)don't(2)"), []);
+}
+
+#[test]
+fn instructions_inside_corruption() {
+    assert_eq!(instructions("mul(mul(2,3)"), [Instruction::Mul(2, 3)]);
+    assert_eq!(instructions("mulmul(4,5))"), [Instruction::Mul(4, 5)]);
+    assert_eq!(instructions("mul(1000,mul(7,8))"), [Instruction::Mul(7, 8)]);
+}
+
+#[test]
+fn spans() {
+    assert_eq!(
+        lex(b"xmul(2,4)&don't()"),
+        [
+            Spanned {
+                instruction: Instruction::Mul(2, 4),
+                span: 1..9,
+            },
+            Spanned {
+                instruction: Instruction::Dont,
+                span: 10..17,
+            },
+        ]
+    );
+}
+
+#[test]
+fn conditionals() {
+    let program = lex(b"mul(2,3)don't()mul(4,5)do()mul(6,7)don't()mul(1,1)");
+    assert_eq!(evaluate(&program, false), 6 + 20 + 42 + 1);
+    assert_eq!(evaluate(&program, true), 6 + 42);
+}
@@ -0,0 +1,82 @@
+use day3::{lex, solve_a, solve_b, sum_reader, Scanner};
+use proptest::prelude::*;
+
+/// Corrupted memory of up to `len` pieces with many instructions that are broken or nearly
+/// broken, like operands with four digits or a missing `)`.
+fn memory(len: usize) -> impl Strategy<Value = Vec<u8>> {
+    const PIECES: &[&str] = &[
+        "mul(",
+        "mul(12,3)",
+        "mul(999,999)",
+        "mul(1000,2)",
+        "do()",
+        "don't()",
+        "don't(",
+        "do(",
+        "mul(7,",
+        "mul(4,56",
+        ")",
+        ",",
+        "1",
+        "23",
+        "x",
+        "%",
+        "do",
+        "mu",
+        "l(",
+        "n't()",
+    ];
+    proptest::collection::vec(proptest::sample::select(PIECES), 0..len)
+        .prop_map(|pieces| pieces.concat().into_bytes())
+}
+
+/// Hands out the memory a few bytes at a time, so instructions are split between reads.
+struct Trickle<'a>(&'a [u8], usize);
+
+impl std::io::Read for Trickle<'_> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let len = self.0.len().min(buf.len()).min(self.1);
+        buf[..len].copy_from_slice(&self.0[..len]);
+        self.0 = &self.0[len..];
+        Ok(len)
+    }
+}
+
+proptest! {
+    #[test]
+    fn chunks_match_the_whole_memory(memory in memory(2_000), chunk_size in 1..100_usize) {
+        let program = lex(&memory);
+        let expected = (solve_a(&program), solve_b(&program));
+
+        let mut scanner = Scanner::new();
+        let mut found = Vec::new();
+        for chunk in memory.chunks(chunk_size) {
+            scanner.feed(chunk, |spanned| found.push(spanned));
+        }
+        scanner.finish(|spanned| found.push(spanned));
+        prop_assert_eq!(found, program);
+
+        prop_assert_eq!(
+            sum_reader(Trickle(&memory, chunk_size), 4096).unwrap(),
+            expected
+        );
+        prop_assert_eq!(sum_reader(&memory[..], chunk_size).unwrap(), expected);
+    }
+}
+
+#[test]
+fn split_instructions() {
+    let memory = b"mul(12,345)don't()mul(2,2)do()mul(3,3)mul(1234,1)";
+    for split in 0..memory.len() {
+        let mut scanner = Scanner::new();
+        let mut found = Vec::new();
+        scanner.feed(&memory[..split], |spanned| found.push(spanned));
+        scanner.feed(&memory[split..], |spanned| found.push(spanned));
+        scanner.finish(|spanned| found.push(spanned));
+        assert_eq!(found, lex(memory), "split at {split}");
+    }
+    assert_eq!(
+        sum_reader(&memory[..], 3).unwrap(),
+        (12 * 345 + 4 + 9, 12 * 345 + 9)
+    );
+}
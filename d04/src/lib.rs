@@ -0,0 +1,59 @@
+use runner::{geometry::Dir8, parse, ParseError, Solution};
+
+mod search;
+
+pub use search::{Occurrence, WordSearch};
+
+/// Part 1 as one configuration of the word search: `XMAS` in all eight directions.
+pub fn solve_a(input: &[Vec<char>]) -> u64 {
+    WordSearch::new(&["XMAS"], &Dir8::ALL).count(input) as u64
+}
+
+/// Part 2 with the word search as well: two diagonal `MAS` that share their `A` form an X.
+pub fn solve_b(input: &[Vec<char>]) -> u64 {
+    let diagonals: Vec<Dir8> = Dir8::ALL
+        .into_iter()
+        .filter(|dir| dir.is_diagonal())
+        .collect();
+
+    let mut num_mas_by_center = vec![vec![0u8; input[0].len()]; input.len()];
+    for occurrence in WordSearch::new(&["MAS"], &diagonals).find_all(input) {
+        let (row, col) = occurrence
+            .start
+            .step(occurrence.dir)
+            .indices()
+            .expect("the A of a MAS is inside the grid");
+        num_mas_by_center[row][col] += 1;
+    }
+
+    // the two diagonals through a center can't both be `MAS` in two directions
+    num_mas_by_center
+        .iter()
+        .flatten()
+        .filter(|&&num_mas| num_mas == 2)
+        .count() as u64
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    parse::grid(input)
+}
+
+pub struct Day4;
+
+impl Solution for Day4 {
+    const DAY: u8 = 4;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        solve_a(input).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(solve_b(input).to_string())
+    }
+}
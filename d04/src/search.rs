@@ -0,0 +1,223 @@
+//! Word search for any dictionary of words in any set of directions. Every direction splits the
+//! grid into rays, the lines of cells from one edge to the other. An Aho–Corasick automaton of the
+//! words walks along every ray once and finds the occurrences of all words at the same time,
+//! however many words there are and however much they overlap.
+
+use std::collections::{HashMap, VecDeque};
+
+use runner::geometry::{Dir8, Pos};
+
+/// An occurrence of a word: the index of the word in the dictionary, the position of its first
+/// letter and the direction it is written in.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Occurrence {
+    pub word: usize,
+    pub start: Pos,
+    pub dir: Dir8,
+}
+
+/// The Aho–Corasick automaton of the dictionary as a complete transition table, so every letter
+/// of a ray takes a single lookup.
+struct Automaton {
+    /// The letters of the words, all other characters are the symbol `alphabet.len()`.
+    alphabet: HashMap<char, usize>,
+    /// The symbols of the ASCII characters, which most grids consist of, without hashing.
+    ascii_symbols: [usize; 128],
+    num_symbols: usize,
+    /// The next state for every state and symbol, at `state * num_symbols + symbol`.
+    transitions: Vec<usize>,
+    /// The words that end in every state, including the ones ending in its suffixes.
+    outputs: Vec<Vec<usize>>,
+}
+
+impl Automaton {
+    const ROOT: usize = 0;
+
+    fn new(words: &[Vec<char>]) -> Self {
+        let mut alphabet = HashMap::new();
+        for &letter in words.iter().flatten() {
+            let next_symbol = alphabet.len();
+            alphabet.entry(letter).or_insert(next_symbol);
+        }
+        let num_symbols = alphabet.len() + 1;
+
+        // the trie of the words, with `None` for missing edges
+        let mut trie: Vec<Vec<Option<usize>>> = vec![vec![None; num_symbols]];
+        let mut outputs: Vec<Vec<usize>> = vec![Vec::new()];
+        for (word_idx, word) in words.iter().enumerate() {
+            let mut state = Self::ROOT;
+            for letter in word {
+                let symbol = alphabet[letter];
+                state = match trie[state][symbol] {
+                    Some(next) => next,
+                    None => {
+                        trie.push(vec![None; num_symbols]);
+                        outputs.push(Vec::new());
+                        trie[state][symbol] = Some(trie.len() - 1);
+                        trie.len() - 1
+                    }
+                };
+            }
+            outputs[state].push(word_idx);
+        }
+
+        // breadth first, so the failure state of a state is complete before the state itself:
+        // missing edges go where the failure state goes and the outputs include its outputs
+        let mut transitions = vec![vec![Self::ROOT; num_symbols]; trie.len()];
+        let mut failure = vec![Self::ROOT; trie.len()];
+        let mut queue = VecDeque::from([Self::ROOT]);
+        while let Some(state) = queue.pop_front() {
+            for symbol in 0..num_symbols {
+                match trie[state][symbol] {
+                    Some(next) => {
+                        transitions[state][symbol] = next;
+                        if state != Self::ROOT {
+                            failure[next] = transitions[failure[state]][symbol];
+                        }
+                        let inherited = outputs[failure[next]].clone();
+                        outputs[next].extend(inherited);
+                        queue.push_back(next);
+                    }
+                    None if state != Self::ROOT => {
+                        transitions[state][symbol] = transitions[failure[state]][symbol];
+                    }
+                    None => {}
+                }
+            }
+        }
+
+        let mut ascii_symbols = [alphabet.len(); 128];
+        for (&letter, &symbol) in &alphabet {
+            if letter.is_ascii() {
+                ascii_symbols[letter as usize] = symbol;
+            }
+        }
+
+        Self {
+            alphabet,
+            ascii_symbols,
+            num_symbols,
+            transitions: transitions.concat(),
+            outputs,
+        }
+    }
+
+    fn symbol(&self, letter: char) -> usize {
+        if letter.is_ascii() {
+            return self.ascii_symbols[letter as usize];
+        }
+        self.alphabet
+            .get(&letter)
+            .copied()
+            .unwrap_or(self.alphabet.len())
+    }
+
+    fn next(&self, state: usize, symbol: usize) -> usize {
+        self.transitions[state * self.num_symbols + symbol]
+    }
+}
+
+/// A dictionary of words and the directions they may be written in.
+pub struct WordSearch {
+    words: Vec<Vec<char>>,
+    dirs: Vec<Dir8>,
+    automaton: Automaton,
+}
+
+impl WordSearch {
+    /// Searches for `words` in the directions `dirs`. The words must not be empty.
+    pub fn new<S: AsRef<str>>(words: &[S], dirs: &[Dir8]) -> Self {
+        let words: Vec<Vec<char>> = words
+            .iter()
+            .map(|word| word.as_ref().chars().collect())
+            .collect();
+        assert!(
+            words.iter().all(|word| !word.is_empty()),
+            "the words must not be empty"
+        );
+
+        let mut dirs = dirs.to_vec();
+        dirs.sort_unstable();
+        dirs.dedup();
+
+        let automaton = Automaton::new(&words);
+        Self {
+            words,
+            dirs,
+            automaton,
+        }
+    }
+
+    /// The number of letters of the word with index `word`.
+    pub fn word_len(&self, word: usize) -> usize {
+        self.words[word].len()
+    }
+
+    /// All occurrences of all words in `grid`, ordered by direction and then along the rays. The
+    /// grid has to be rectangular, like the ones of `parse::grid`.
+    pub fn find_all(&self, grid: &[Vec<char>]) -> Vec<Occurrence> {
+        let mut occurrences = Vec::new();
+        self.for_each(grid, |occurrence| occurrences.push(occurrence));
+        occurrences
+    }
+
+    pub fn count(&self, grid: &[Vec<char>]) -> usize {
+        let mut count = 0;
+        self.for_each(grid, |_| count += 1);
+        count
+    }
+
+    fn for_each(&self, grid: &[Vec<char>], mut found: impl FnMut(Occurrence)) {
+        let num_rows = grid.len();
+        let num_cols = grid.first().map_or(0, Vec::len);
+        let symbols: Vec<usize> = grid
+            .iter()
+            .flat_map(|row| row.iter().map(|&c| self.automaton.symbol(c)))
+            .collect();
+
+        // the number of cells from `idx` to the edge of an axis of length `len` in direction `delta`
+        let cells_to_edge = |idx: usize, delta: isize, len: usize| match delta {
+            1 => len - idx,
+            -1 => idx + 1,
+            _ => usize::MAX,
+        };
+
+        // a ray starts at a cell whose predecessor in its direction is outside the grid, which
+        // is always on the border
+        let border: Vec<(usize, usize)> = (0..num_rows)
+            .flat_map(|row| (0..num_cols).map(move |col| (row, col)))
+            .filter(|&(row, col)| {
+                row == 0 || col == 0 || row + 1 == num_rows || col + 1 == num_cols
+            })
+            .collect();
+
+        for &dir in &self.dirs {
+            let offset = dir.offset();
+            let stride = offset.row * num_cols as isize + offset.col;
+
+            for &(row, col) in &border {
+                let ray_start = Pos::from_indices(row, col);
+                if (ray_start - offset).in_bounds(num_rows, num_cols) {
+                    continue;
+                }
+
+                let ray_len = cells_to_edge(row, offset.row, num_rows)
+                    .min(cells_to_edge(col, offset.col, num_cols));
+                let mut state = Automaton::ROOT;
+                let mut idx = row * num_cols + col;
+                for len in 1..=ray_len {
+                    state = self.automaton.next(state, symbols[idx]);
+                    for &word in &self.automaton.outputs[state] {
+                        let start_idx = (len - self.words[word].len()) as isize;
+                        found(Occurrence {
+                            word,
+                            start: ray_start + offset * start_idx,
+                            dir,
+                        });
+                    }
+                    idx = idx.wrapping_add_signed(stride);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,119 @@
+use day4::{parse, Occurrence, WordSearch};
+use proptest::prelude::*;
+use runner::geometry::{Dir8, Pos};
+
+/// Checks every start, direction and word letter by letter.
+fn find_all_brute_force(words: &[&str], dirs: &[Dir8], grid: &[Vec<char>]) -> Vec<Occurrence> {
+    let mut occurrences = Vec::new();
+    for (word_idx, word) in words.iter().enumerate() {
+        for row in 0..grid.len() {
+            for col in 0..grid[0].len() {
+                for &dir in dirs {
+                    let start = Pos::from_indices(row, col);
+                    let is_match = word
+                        .chars()
+                        .enumerate()
+                        .all(|(k, c)| (start + dir.offset() * k as isize).get(grid) == Some(&c));
+                    if is_match {
+                        occurrences.push(Occurrence {
+                            word: word_idx,
+                            start,
+                            dir,
+                        });
+                    }
+                }
+            }
+        }
+    }
+    occurrences.sort();
+    occurrences
+}
+
+const DICTIONARIES: [&[&str]; 4] = [
+    &["XMAS"],
+    &["AB", "ABA", "BAB", "B", "ABAB"],
+    &["ABC", "BC", "C", "CAB", "ABC"],
+    &["AAAA", "AA", "XA"],
+];
+
+/// A rectangular grid of up to 25 rows and columns of the letters of `words` and some other
+/// letter, so the words occur often.
+fn grid(words: &[&str]) -> impl Strategy<Value = Vec<Vec<char>>> {
+    let mut letters: Vec<char> = words.iter().flat_map(|word| word.chars()).collect();
+    letters.push('.');
+    letters.sort_unstable();
+    letters.dedup();
+
+    (1..25_usize, 1..25_usize).prop_flat_map(move |(num_rows, num_cols)| {
+        let row = proptest::collection::vec(proptest::sample::select(letters.clone()), num_cols);
+        proptest::collection::vec(row, num_rows)
+    })
+}
+
+fn dirs() -> impl Strategy<Value = Vec<Dir8>> {
+    let straight: Vec<Dir8> = Dir8::ALL
+        .into_iter()
+        .filter(|dir| !dir.is_diagonal())
+        .collect();
+    prop_oneof![
+        Just(Dir8::ALL.to_vec()),
+        Just(straight),
+        Just(vec![Dir8::DownLeft]),
+        proptest::sample::subsequence(Dir8::ALL.to_vec(), 0..=8),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn matches_the_brute_force(
+        (words, grid) in proptest::sample::select(&DICTIONARIES[..])
+            .prop_flat_map(|words| (Just(words), grid(words))),
+        dirs in dirs(),
+    ) {
+        let search = WordSearch::new(words, &dirs);
+        let mut occurrences = search.find_all(&grid);
+        occurrences.sort();
+        prop_assert_eq!(&occurrences, &find_all_brute_force(words, &dirs, &grid));
+        prop_assert_eq!(search.count(&grid), occurrences.len());
+    }
+}
+
+#[test]
+fn positions_and_directions() {
+    let grid = parse("XMAS\nMM..\nA.A.\nS..S\n").unwrap();
+    let search = WordSearch::new(&["XMAS", "SAM"], &Dir8::ALL);
+    let mut occurrences = search.find_all(&grid);
+    occurrences.sort();
+
+    let occurrence = |word, row, col, dir| Occurrence {
+        word,
+        start: Pos::new(row, col),
+        dir,
+    };
+    assert_eq!(
+        occurrences,
+        [
+            occurrence(0, 0, 0, Dir8::Right),
+            occurrence(0, 0, 0, Dir8::DownRight),
+            occurrence(0, 0, 0, Dir8::Down),
+            occurrence(1, 0, 3, Dir8::Left),
+            occurrence(1, 3, 0, Dir8::Up),
+            occurrence(1, 3, 3, Dir8::UpLeft),
+        ]
+    );
+    assert_eq!(search.word_len(1), 3);
+}
+
+#[test]
+fn example() {
+    let grid = parse(
+        &std::fs::read_to_string(concat!(env!("CARGO_MANIFEST_DIR"), "/example.txt")).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(day4::solve_a(&grid), 18);
+    assert_eq!(day4::solve_b(&grid), 9);
+    assert_eq!(
+        WordSearch::new(&["XMAS", "SAMX"], &[Dir8::Right]).count(&grid),
+        5
+    );
+}
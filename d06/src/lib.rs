@@ -0,0 +1,111 @@
+use std::collections::HashSet;
+
+use runner::{
+    geometry::{Dir, Pos},
+    par, parse, ParseError, Solution,
+};
+
+fn find_guard(map: &[Vec<char>]) -> Pos {
+    let mut guard = Pos::ZERO;
+    for (line_idx, line) in map.iter().enumerate() {
+        if line.contains(&'^') {
+            guard = Pos::from_indices(line_idx, line.iter().position(|c| *c == '^').unwrap());
+        }
+    }
+    guard
+}
+
+pub fn solve_a(map: &[Vec<char>]) -> u64 {
+    let mut path = HashSet::new();
+
+    let mut pos = find_guard(map);
+    let mut dir = Dir::Up;
+
+    loop {
+        path.insert(pos);
+
+        let next = pos.step(dir);
+        match next.get(map) {
+            Some('^' | '.') => pos = next,
+            Some('#') => dir = dir.turn_right(),
+            None => break,
+            _ => unreachable!(),
+        }
+    }
+
+    path.len() as u64
+}
+
+/// Whether an obstruction at `m_row`, `m_col` traps the guard in a loop.
+fn is_option(orig_map: &[Vec<char>], m_row: usize, m_col: usize) -> bool {
+    let mut map = orig_map.to_vec();
+    map[m_row][m_col] = '#';
+    let mut path = HashSet::new();
+
+    let mut pos = find_guard(&map);
+    if pos == Pos::from_indices(m_row, m_col) {
+        return false;
+    }
+
+    let mut dir = Dir::Up;
+
+    loop {
+        if !path.insert((pos, dir)) {
+            break true;
+        }
+
+        let next = pos.step(dir);
+        match next.get(&map) {
+            Some('^' | '.') => pos = next,
+            Some('#') => dir = dir.turn_right(),
+            None => break false,
+            _ => unreachable!(),
+        }
+    }
+}
+
+pub fn solve_b(orig_map: &[Vec<char>]) -> u64 {
+    let num_cols = orig_map[0].len();
+    par::count(0..orig_map.len() * num_cols, |cell| {
+        is_option(orig_map, cell / num_cols, cell % num_cols)
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    let mut num_guards = 0;
+    let map = parse::grid_with(input, |_, _, c| match c {
+        '.' | '#' => Ok(c),
+        '^' => {
+            num_guards += 1;
+            Ok(c)
+        }
+        _ => Err(ParseError::unexpected_char(c)),
+    })?;
+
+    if num_guards != 1 {
+        return Err(ParseError::new(format!(
+            "expected one guard, found {num_guards}"
+        )));
+    }
+    Ok(map)
+}
+
+pub struct Day6;
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> String {
+        solve_a(map).to_string()
+    }
+
+    fn part2(map: &Self::Input) -> Option<String> {
+        Some(solve_b(map).to_string())
+    }
+}
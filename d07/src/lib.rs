@@ -0,0 +1,145 @@
+use runner::{overflow, par, parse, ParseError, Solution};
+
+fn solve_a_helper(value_left: u64, inputs_left: &[u64]) -> bool {
+    if inputs_left.is_empty() {
+        println!("Finished recursion with inputs_left.len() == 0!");
+        return value_left == 0;
+    }
+
+    if inputs_left.len() == 1 {
+        return value_left == inputs_left[0];
+    }
+
+    let (last_input, rest_inputs) = inputs_left.split_last().unwrap();
+
+    if value_left.is_multiple_of(*last_input)
+        && solve_a_helper(value_left / last_input, rest_inputs)
+    {
+        return true;
+    }
+
+    if value_left <= *last_input {
+        return false;
+    }
+
+    solve_a_helper(value_left - last_input, rest_inputs)
+}
+
+fn find_next_power_10(mut num: u64) -> u64 {
+    assert!(num != 0);
+
+    let mut power = 1u64;
+    while num > 0 {
+        num /= 10;
+        power = overflow::mul(7, "power * 10", power, 10);
+    }
+
+    power
+}
+
+fn solve_b_helper(value_left: u64, inputs_left: &[u64]) -> bool {
+    if inputs_left.is_empty() {
+        println!("Finished recursion with inputs_left.len() == 0!");
+        return value_left == 0;
+    }
+
+    if inputs_left.len() == 1 {
+        return value_left == inputs_left[0];
+    }
+
+    let (last_input, rest_inputs) = inputs_left.split_last().unwrap();
+
+    if value_left.is_multiple_of(*last_input)
+        && solve_b_helper(value_left / last_input, rest_inputs)
+    {
+        return true;
+    }
+
+    if value_left > *last_input && solve_b_helper(value_left - last_input, rest_inputs) {
+        return true;
+    }
+
+    let next_power_10_last_input = find_next_power_10(*last_input);
+    if value_left % next_power_10_last_input == *last_input
+        && solve_b_helper(value_left / next_power_10_last_input, rest_inputs)
+    {
+        return true;
+    }
+
+    false
+}
+
+pub fn solve_a(calibrations: &[Calibration]) -> u64 {
+    par::map_reduce(
+        calibrations,
+        0,
+        |calibration| {
+            if solve_a_helper(calibration.test_value, &calibration.inputs) {
+                calibration.test_value
+            } else {
+                0
+            }
+        },
+        |sum, test_value| overflow::add(7, "sum + test_value", sum, test_value),
+    )
+}
+
+pub fn solve_b(calibrations: &[Calibration]) -> u64 {
+    par::map_reduce(
+        calibrations,
+        0,
+        |calibration| {
+            if solve_b_helper(calibration.test_value, &calibration.inputs) {
+                calibration.test_value
+            } else {
+                0
+            }
+        },
+        |sum, test_value| overflow::add(7, "sum + test_value", sum, test_value),
+    )
+}
+
+#[derive(Clone)]
+pub struct Calibration {
+    test_value: u64,
+    inputs: Vec<u64>,
+}
+
+pub fn parse(input: &str) -> Result<Vec<Calibration>, ParseError> {
+    parse::lines(input, |line| {
+        let Some((test_value, inputs)) = line.split_once(':') else {
+            return Err(ParseError::new("expected `test value: inputs`"));
+        };
+
+        let test_value = parse::number(test_value.trim())?;
+        let inputs: Vec<u64> = inputs
+            .split_whitespace()
+            .map(parse::number)
+            .collect::<Result<_, _>>()?;
+        if inputs.is_empty() {
+            return Err(ParseError::new("a calibration needs at least one input"));
+        }
+
+        Ok(Calibration { test_value, inputs })
+    })
+}
+
+pub struct Day7;
+
+impl Solution for Day7 {
+    const DAY: u8 = 7;
+
+    type Input = Vec<Calibration>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(calibrations: &Self::Input) -> String {
+        solve_a(calibrations).to_string()
+    }
+
+    fn part2(calibrations: &Self::Input) -> Option<String> {
+        Some(solve_b(calibrations).to_string())
+    }
+}
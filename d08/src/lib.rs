@@ -0,0 +1,138 @@
+use std::collections::{HashMap, HashSet};
+
+use runner::{geometry::Pos, parse, ParseError, Solution};
+
+pub fn print_antennas_antinodes(
+    antennas: &HashMap<char, Vec<Pos>>,
+    antinodes: &HashSet<Pos>,
+    num_rows: usize,
+    num_cols: usize,
+) {
+    let mut buf = Vec::new();
+
+    for _ in 0..num_rows {
+        buf.push(Vec::from_iter(std::iter::repeat_n('.', num_cols)));
+    }
+
+    for (c, positions) in antennas {
+        for pos in positions {
+            buf[pos.row as usize][pos.col as usize] = *c;
+        }
+    }
+
+    for antinode in antinodes {
+        buf[antinode.row as usize][antinode.col as usize] = '#';
+    }
+
+    for row in buf {
+        for c in row {
+            print!("{c}");
+        }
+        println!();
+    }
+}
+
+pub fn solve(
+    antennas: &HashMap<char, Vec<Pos>>,
+    num_rows: usize,
+    num_cols: usize,
+    restrict_distance: bool,
+) -> u64 {
+    let is_pos_in_bounds = |pos: Pos| pos.in_bounds(num_rows, num_cols);
+
+    let mut antinodes: HashSet<Pos> = HashSet::new();
+    for positions in antennas.values() {
+        for i in 0..positions.len() {
+            for j in (i + 1)..positions.len() {
+                let a = positions[i];
+                let b = positions[j];
+                let diff = b - a;
+
+                if restrict_distance {
+                    let loc_a = a - diff;
+                    let loc_b = b + diff;
+
+                    if is_pos_in_bounds(loc_a) {
+                        antinodes.insert(loc_a);
+                    }
+
+                    if is_pos_in_bounds(loc_b) {
+                        antinodes.insert(loc_b);
+                    }
+                } else {
+                    let mut cur_pos = a;
+                    loop {
+                        if !is_pos_in_bounds(cur_pos) {
+                            break;
+                        }
+
+                        antinodes.insert(cur_pos);
+                        cur_pos += diff;
+                    }
+
+                    let mut cur_pos = a;
+                    loop {
+                        if !is_pos_in_bounds(cur_pos) {
+                            break;
+                        }
+
+                        antinodes.insert(cur_pos);
+                        cur_pos -= diff;
+                    }
+                }
+            }
+        }
+    }
+
+    // print_antennas_antinodes(antennas, &antinodes, num_rows, num_cols);
+
+    antinodes.len() as u64
+}
+
+pub struct Antennas {
+    pub antennas: HashMap<char, Vec<Pos>>,
+    pub num_rows: usize,
+    pub num_cols: usize,
+}
+
+pub fn parse(input: &str) -> Result<Antennas, ParseError> {
+    let mut antennas = HashMap::new();
+
+    let map = parse::grid_with(input, |row_idx, col_idx, c| {
+        if c.is_ascii_alphanumeric() {
+            antennas
+                .entry(c)
+                .or_insert(Vec::new())
+                .push(Pos::from_indices(row_idx, col_idx));
+        } else if c != '.' {
+            return Err(ParseError::unexpected_char(c));
+        }
+        Ok(())
+    })?;
+
+    Ok(Antennas {
+        antennas,
+        num_rows: map.len(),
+        num_cols: map[0].len(),
+    })
+}
+
+pub struct Day8;
+
+impl Solution for Day8 {
+    const DAY: u8 = 8;
+
+    type Input = Antennas;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        solve(&input.antennas, input.num_rows, input.num_cols, true).to_string()
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        Some(solve(&input.antennas, input.num_rows, input.num_cols, false).to_string())
+    }
+}
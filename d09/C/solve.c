@@ -1,5 +1,6 @@
 #include <assert.h>
 #include <fcntl.h>
+#include <inttypes.h>
 #include <stdint.h>
 #include <stdio.h>
 #include <stdlib.h>
@@ -9,6 +10,10 @@
 #include <time.h>
 #include <unistd.h>
 
+#ifdef WITH_RUST
+#include "aoc.h"
+#endif
+
 char *map_file(const char *file_path, size_t *file_size) {
   int fd = open(file_path, O_RDONLY);
   if (fd == -1) {
@@ -233,8 +238,8 @@ int main() {
       solve_b(frees_clone, files_clone, frees_len, files_len, file_size);
   clock_gettime(CLOCK_MONOTONIC, &end_b);
 
-  printf("Task1: %ld\n", output_a);
-  printf("Task2: %ld\n", output_b);
+  printf("Task1: %" PRIu64 "\n", output_a);
+  printf("Task2: %" PRIu64 "\n", output_b);
 
   printf("Input loading took: : %lus, %luµs\n",
          end_input.tv_sec - start_input.tv_sec,
@@ -251,6 +256,24 @@ int main() {
     return EXIT_FAILURE;
   }
 
+#ifdef WITH_RUST
+  aoc_answer_t rust;
+  if (aoc_solve(9, input, file_size, &rust) != AOC_OK) {
+    fprintf(stderr, "rust solver failed\n");
+    return EXIT_FAILURE;
+  }
+
+  printf("Rust Task1: %s (%s)\n", rust.part1,
+         strtoull(rust.part1, NULL, 10) == output_a ? "same" : "DIFFERENT");
+  printf("Rust Task2: %s (%s)\n", rust.part2,
+         strtoull(rust.part2, NULL, 10) == output_b ? "same" : "DIFFERENT");
+  printf("Rust parsing took: %" PRIu64 "µs\n", rust.parse_ns / 1000);
+  printf("Rust Task 1 took: %" PRIu64 "µs\n", rust.part1_ns / 1000);
+  printf("Rust Task 2 took: %" PRIu64 "µs\n", rust.part2_ns / 1000);
+
+  aoc_answer_free(&rust);
+#endif
+
   if (munmap(input, file_size) == -1) {
     perror("munmap");
     return EXIT_FAILURE;
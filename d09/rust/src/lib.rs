@@ -0,0 +1,177 @@
+use runner::{overflow, ParseError, Solution};
+
+#[derive(Clone, Copy, Debug)]
+pub struct Space {
+    pos: usize,
+    len: usize,
+    id: Option<usize>,
+}
+
+pub fn solve_a(mut files: Vec<Space>, mut free_list: Vec<Space>, total_size: usize) -> usize {
+    let mut sum = 0;
+
+    let mut disk_idx = 0;
+    while disk_idx < total_size {
+        if files.is_empty() {
+            break;
+        }
+        if files[0].pos <= disk_idx {
+            assert!(files[0].pos == disk_idx);
+            let f = files.remove(0);
+            for f_pos in f.pos..(f.pos + f.len) {
+                // print!("{}", f.id.unwrap());
+                let checksum = overflow::mul(9, "id * pos", f.id.unwrap(), f_pos);
+                sum = overflow::add(9, "sum + id * pos", sum, checksum);
+            }
+
+            disk_idx += f.len;
+            continue;
+        }
+
+        let mut free = free_list.remove(0);
+        if free.pos != disk_idx {
+            println!("{:?}", files[0]);
+            println!("{free:?}");
+        }
+        assert_eq!(free.pos, disk_idx);
+
+        while free.len > 0 {
+            if files.is_empty() {
+                break;
+            }
+            let mut f = files.pop().unwrap();
+
+            let moved_len = free.len.min(f.len);
+
+            // println!("\nmoved_len: {moved_len} (id = {})", f.id.unwrap());
+
+            for pos in disk_idx..(disk_idx + moved_len) {
+                // print!("{}", f.id.unwrap());
+                let checksum = overflow::mul(9, "pos * id", pos, f.id.unwrap());
+                sum = overflow::add(9, "sum + pos * id", sum, checksum);
+            }
+
+            disk_idx += moved_len;
+            free.len -= moved_len;
+            f.len -= moved_len;
+
+            if f.len > 0 {
+                files.push(f);
+                break;
+            }
+        }
+
+        if !files.is_empty() {
+            assert_eq!(free.len, 0);
+        }
+    }
+
+    // println!("");
+
+    sum
+}
+
+pub fn solve_b(files: Vec<Space>, mut free_list: Vec<Space>) -> usize {
+    let mut processed_files = Vec::new();
+
+    for mut file in files.into_iter().rev() {
+        for free_idx in 0..free_list.len() {
+            if free_list[free_idx].pos < file.pos && free_list[free_idx].len >= file.len {
+                file.pos = free_list[free_idx].pos;
+
+                free_list[free_idx].pos += file.len;
+                free_list[free_idx].len -= file.len;
+
+                if free_list[free_idx].len == 0 {
+                    free_list.remove(free_idx);
+                }
+                break;
+            }
+        }
+        processed_files.push(file);
+    }
+
+    let mut sum = 0;
+
+    for file in processed_files {
+        for p in file.pos..(file.pos + file.len) {
+            let checksum = overflow::mul(9, "pos * id", p, file.id.unwrap());
+            sum = overflow::add(9, "sum + pos * id", sum, checksum);
+        }
+    }
+
+    sum
+}
+
+pub struct Disk {
+    pub files: Vec<Space>,
+    pub free_list: Vec<Space>,
+    pub total_size: usize,
+}
+
+pub fn parse(input: &str) -> Result<Disk, ParseError> {
+    let input_nums: Vec<usize> = input
+        .trim_end()
+        .chars()
+        .map(|c: char| {
+            c.to_digit(10)
+                .map(|digit| digit as usize)
+                .ok_or(ParseError::unexpected_char(c))
+        })
+        .collect::<Result<_, _>>()?;
+
+    let mut free_list: Vec<Space> = Vec::new();
+    let mut files: Vec<Space> = Vec::new();
+    let mut total_size = 0;
+    let mut next_file_id = 0;
+
+    for (idx, num) in input_nums.into_iter().enumerate() {
+        if num == 0 {
+            continue;
+        }
+        match idx % 2 {
+            1 => free_list.push(Space {
+                pos: total_size,
+                len: num,
+                id: None,
+            }),
+            0 => {
+                files.push(Space {
+                    pos: total_size,
+                    len: num,
+                    id: Some(next_file_id),
+                });
+                next_file_id += 1;
+            }
+            _ => unreachable!(),
+        }
+
+        total_size += num;
+    }
+
+    Ok(Disk {
+        files,
+        free_list,
+        total_size,
+    })
+}
+
+pub struct Day9;
+
+impl Solution for Day9 {
+    const DAY: u8 = 9;
+
+    type Input = Disk;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(disk: &Self::Input) -> String {
+        solve_a(disk.files.clone(), disk.free_list.clone(), disk.total_size).to_string()
+    }
+
+    fn part2(disk: &Self::Input) -> Option<String> {
+        Some(solve_b(disk.files.clone(), disk.free_list.clone()).to_string())
+    }
+}
@@ -1,7 +1,8 @@
+use runner::{parse, ParseError, Solution};
 use rustc_hash::FxHashSet as HashSet;
 use std::path::Path;
 
-pub fn solve(map: &Vec<Vec<u32>>, trailheads: &HashSet<(isize, isize)>) -> (u32, u32) {
+pub fn solve(map: &[Vec<u32>], trailheads: &HashSet<(isize, isize)>) -> (u32, u32) {
     let num_rows = map.len() as isize;
     let num_cols = map[0].len() as isize;
 
@@ -52,33 +53,38 @@ pub fn read_input<P: AsRef<Path>>(path: P) -> String {
     std::fs::read_to_string(path).unwrap()
 }
 
-pub fn parse(input: &String) -> (Vec<Vec<u32>>, HashSet<(isize, isize)>) {
+/// The heights of the map and the positions of the trailheads on it.
+pub type TopographicMap = (Vec<Vec<u32>>, HashSet<(isize, isize)>);
+
+pub fn parse(input: &str) -> Result<TopographicMap, ParseError> {
     let mut trailheads = HashSet::default();
-    let map = input
-        .lines()
-        .enumerate()
-        .map(|(row_idx, row)| {
-            row.chars()
-                .enumerate()
-                .map(|(col_idx, c)| match c {
-                    '0' => {
-                        trailheads.insert((row_idx as isize, col_idx as isize));
-                        0
-                    }
-                    '1' => 1,
-                    '2' => 2,
-                    '3' => 3,
-                    '4' => 4,
-                    '5' => 5,
-                    '6' => 6,
-                    '7' => 7,
-                    '8' => 8,
-                    '9' => 9,
-                    _ => unreachable!(),
-                })
-                .collect()
-        })
-        .collect();
-
-    (map, trailheads)
+    let map = parse::grid_with(input, |row_idx, col_idx, c| {
+        let height = c.to_digit(10).ok_or(ParseError::unexpected_char(c))?;
+        if height == 0 {
+            trailheads.insert((row_idx as isize, col_idx as isize));
+        }
+        Ok(height)
+    })?;
+
+    Ok((map, trailheads))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = TopographicMap;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1((map, trailheads): &Self::Input) -> String {
+        solve(map, trailheads).0.to_string()
+    }
+
+    fn part2((map, trailheads): &Self::Input) -> Option<String> {
+        Some(solve(map, trailheads).1.to_string())
+    }
 }
@@ -1,11 +1,12 @@
 use criterion::{criterion_group, criterion_main, Criterion};
 use day11::{
-    calc_lookup_tables, parse_stones, solve_lookup, solve_memoization, solve_smart, solve_smart_fast, solve_stupid
+    calc_lookup_tables, parse_stones, solve_lookup, solve_memoization, solve_smart,
+    solve_smart_fast, solve_stupid,
 };
 use std::hint::black_box;
 
 fn day11_benches(c: &mut Criterion) {
-    let input_stones = parse_stones("../input.txt");
+    let input_stones = parse_stones("../input.txt").unwrap();
 
     let lookup_tables_25_10 = calc_lookup_tables::<10>(25);
     let lookup_tables_75_10 = calc_lookup_tables::<10>(75);
@@ -1,20 +1,17 @@
 use std::path::Path;
 
-use regex::Regex;
-
+use runner::{overflow, parse, ParseError, Solution};
 use rustc_hash::FxHashMap as HashMap;
 
 pub type StoneT = u64;
 
-pub fn parse_stones<P: AsRef<Path>>(path: P) -> Vec<StoneT> {
+pub fn parse_stones<P: AsRef<Path>>(path: P) -> Result<Vec<StoneT>, ParseError> {
     let input = std::fs::read_to_string(path).unwrap();
+    parse(&input)
+}
 
-    let num_re = Regex::new(r"(\d+)").unwrap();
-
-    num_re
-        .find_iter(&input)
-        .map(|m| m.as_str().parse().unwrap())
-        .collect()
+pub fn parse(input: &str) -> Result<Vec<StoneT>, ParseError> {
+    input.split_whitespace().map(parse::number).collect()
 }
 
 fn perform_iteration(stones: &mut Vec<StoneT>) {
@@ -27,13 +24,13 @@ fn perform_iteration(stones: &mut Vec<StoneT>) {
         } else {
             let num_digits = stone.ilog10() + 1;
 
-            if num_digits % 2 == 0 {
+            if num_digits.is_multiple_of(2) {
                 let split_pow = StoneT::pow(10, num_digits / 2);
                 stones[idx] = stone / split_pow;
                 idx += 1;
                 stones.insert(idx, stone % split_pow);
             } else {
-                stones[idx] *= 2024
+                stones[idx] = overflow::mul(11, "stone * 2024", stones[idx], 2024)
             }
         }
 
@@ -65,12 +62,15 @@ pub fn solve_smart<const NUM_ITER: u32>(stones: Vec<StoneT>) -> u64 {
 
         let num_digits = stone.ilog10() + 1;
 
-        if num_digits % 2 == 0 {
+        if num_digits.is_multiple_of(2) {
             let split_pow = StoneT::pow(10, num_digits / 2);
             stones.push((stone / split_pow, iteration + 1));
             stones.push((stone % split_pow, iteration + 1));
         } else {
-            stones.push((stone * 2024, iteration + 1))
+            stones.push((
+                overflow::mul(11, "stone * 2024", stone, 2024),
+                iteration + 1,
+            ))
         }
     }
 
@@ -109,7 +109,7 @@ pub fn solve_smart_fast<const NUM_ITER: u32>(stones: Vec<StoneT>) -> u64 {
 
         let num_digits = stone.ilog10() + 1;
 
-        if num_digits % 2 == 0 {
+        if num_digits.is_multiple_of(2) {
             let first = match num_digits {
                 2 => compute_div::<10>(stone),
                 4 => compute_div::<100>(stone),
@@ -136,7 +136,7 @@ pub fn solve_smart_fast<const NUM_ITER: u32>(stones: Vec<StoneT>) -> u64 {
                 _ => unreachable!(),
             };
         } else {
-            stone *= 2024;
+            stone = overflow::mul(11, "stone * 2024", stone, 2024);
         }
 
         iteration += 1;
@@ -148,7 +148,7 @@ pub fn solve_smart_fast<const NUM_ITER: u32>(stones: Vec<StoneT>) -> u64 {
 pub fn solve_lookup<const LOOKUP_TABLE_SIZE: usize>(
     stones: Vec<StoneT>,
     num_iter: usize,
-    lookup_tables: &Vec<[u64; LOOKUP_TABLE_SIZE]>,
+    lookup_tables: &[[u64; LOOKUP_TABLE_SIZE]],
 ) -> u64 {
     let mut count_stones = 0;
     let mut stones: Vec<(StoneT, usize)> =
@@ -185,7 +185,7 @@ pub fn solve_lookup<const LOOKUP_TABLE_SIZE: usize>(
 
         let num_digits = stone.ilog10() + 1;
 
-        if num_digits % 2 == 0 {
+        if num_digits.is_multiple_of(2) {
             let first = match num_digits {
                 2 => compute_div::<10>(stone),
                 4 => compute_div::<100>(stone),
@@ -212,7 +212,7 @@ pub fn solve_lookup<const LOOKUP_TABLE_SIZE: usize>(
                 _ => unreachable!(),
             };
         } else {
-            stone *= 2024;
+            stone = overflow::mul(11, "stone * 2024", stone, 2024);
         }
 
         iter_left -= 1;
@@ -254,17 +254,21 @@ fn solve_memoization_rec(
     } else {
         let num_digits = stone.ilog10() + 1;
 
-        if num_digits % 2 == 0 {
+        if num_digits.is_multiple_of(2) {
             let split_pow = StoneT::pow(10, num_digits / 2);
             solve_memoization_rec(stone / split_pow, num_iter - 1, cache)
                 + solve_memoization_rec(stone % split_pow, num_iter - 1, cache)
         } else {
-            solve_memoization_rec(stone * 2024, num_iter - 1, cache)
+            solve_memoization_rec(
+                overflow::mul(11, "stone * 2024", stone, 2024),
+                num_iter - 1,
+                cache,
+            )
         }
     };
 
     cache.insert((stone, num_iter), prod_stones);
-    return prod_stones;
+    prod_stones
 }
 
 pub fn solve_memoization(stones: Vec<StoneT>, num_iter: u64) -> u64 {
@@ -276,3 +280,24 @@ pub fn solve_memoization(stones: Vec<StoneT>, num_iter: u64) -> u64 {
     }
     count_stones
 }
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<StoneT>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(stones: &Self::Input) -> String {
+        solve_smart::<25>(stones.clone()).to_string()
+    }
+
+    fn part2(stones: &Self::Input) -> Option<String> {
+        let lookup_tables = calc_lookup_tables::<10>(75);
+        Some(solve_lookup(stones.clone(), 75, &lookup_tables).to_string())
+    }
+}
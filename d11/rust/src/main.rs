@@ -1,12 +1,22 @@
 use std::time::Instant;
 
 use day11::{
-    calc_lookup_tables, parse_stones, solve_lookup, solve_smart, solve_smart_fast, solve_stupid, solve_memoization
+    calc_lookup_tables, parse, solve_lookup, solve_memoization, solve_smart, solve_smart_fast,
+    Day11,
 };
 
-fn main() {
-    let stones = parse_stones("../input.txt");
-    // let stones = parse_stones("../example.txt");
+const USAGE: &str = "usage: day11 [INPUT] [--strategies]";
+
+/// Solves the input of `selection` with every strategy, checks that they agree and prints their
+/// timings.
+fn compare_strategies(day_dir: &str, selection: &str) {
+    let (selected, input) = runner::load_selection(day_dir, selection);
+    let stones = parse(&runner::text::normalize(&input)).unwrap_or_else(|err| {
+        runner::exit_with(format!(
+            "Cannot parse input {}: {err}",
+            selected.file.display()
+        ))
+    });
 
     println!("Initial: {stones:?}");
 
@@ -56,7 +66,33 @@ fn main() {
         elapsed_a_smart_fast.as_micros()
     );
     println!("Task 1 (lookup):\t{}µs", elapsed_a_lookup.as_micros());
-    println!("Task 1 (memoization):\t{}µs", elapsed_a_memoization.as_micros());
+    println!(
+        "Task 1 (memoization):\t{}µs",
+        elapsed_a_memoization.as_micros()
+    );
     println!("Task 2 (lookup):\t{}µs", elapsed_b_lookup.as_micros());
-    println!("Task 2 (memoization):\t{}µs", elapsed_b_memoization.as_micros());
+    println!(
+        "Task 2 (memoization):\t{}µs",
+        elapsed_b_memoization.as_micros()
+    );
+}
+
+fn main() {
+    let day_dir = concat!(env!("CARGO_MANIFEST_DIR"), "/..");
+
+    let mut selection = "input".to_string();
+    let mut strategies = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "--strategies" => strategies = true,
+            _ if arg.starts_with("--") => runner::exit_with(USAGE),
+            _ => selection = arg,
+        }
+    }
+
+    if strategies {
+        compare_strategies(day_dir, &selection);
+    } else {
+        runner::run_selection::<Day11>(day_dir, &selection);
+    }
 }
@@ -0,0 +1,319 @@
+use std::{collections::HashMap, fmt::Display};
+
+use runner::{
+    geometry::{Axis, Dir, Pos},
+    parse, ParseError, Solution,
+};
+
+fn find_stats_a(
+    map: &[Vec<char>],
+    visited: &mut [Vec<bool>],
+    row: usize,
+    col: usize,
+    c: char,
+) -> (usize, usize) {
+    if map[row][col] != c {
+        return (0, 0);
+    }
+
+    visited[row][col] = true;
+
+    let mut region_area = 1;
+    let mut region_perimeter = 0;
+
+    for neighbor in Pos::from_indices(row, col).neighbors4() {
+        if neighbor.get(map) != Some(&c) {
+            region_perimeter += 1;
+            continue;
+        }
+
+        let (n_row, n_col) = neighbor.indices().unwrap();
+        if !visited[n_row][n_col] {
+            let (sub_area, sub_perimeter) = find_stats_a(map, visited, n_row, n_col, c);
+            region_area += sub_area;
+            region_perimeter += sub_perimeter;
+        }
+    }
+
+    (region_area, region_perimeter)
+}
+
+pub fn solve_a(map: &[Vec<char>]) -> usize {
+    let num_cols = map[0].len();
+    let mut visited = vec![vec![false; num_cols]; map.len()];
+
+    let mut sum = 0;
+
+    for row in 0..map.len() {
+        for col in 0..num_cols {
+            if visited[row][col] {
+                continue;
+            }
+
+            let (region_area, region_perimeter) =
+                find_stats_a(map, &mut visited, row, col, map[row][col]);
+            sum += region_area * region_perimeter;
+        }
+    }
+
+    sum
+}
+
+fn find_stats_b(
+    map: &[Vec<char>],
+    visited: &mut [Vec<bool>],
+    perimeter: &mut HashMap<Axis, Vec<(usize, usize)>>,
+    row: usize,
+    col: usize,
+    c: char,
+) -> usize {
+    if map[row][col] != c {
+        return 0;
+    }
+
+    visited[row][col] = true;
+
+    // the fence below or right of a plot is stored at the neighboring plot
+    const DIRECTIONS: [(Dir, bool); 4] = [
+        (Dir::Up, false),
+        (Dir::Down, true),
+        (Dir::Left, false),
+        (Dir::Right, true),
+    ];
+
+    let mut region_area = 1;
+
+    for (dir, use_new) in DIRECTIONS {
+        let n_row = row as isize + dir.offset().row;
+        let n_col = col as isize + dir.offset().col;
+        let side_axis = dir.axis().perpendicular();
+
+        if n_row < 0 || n_row >= map.len() as isize || n_col < 0 || n_col >= map[0].len() as isize {
+            if use_new {
+                assert!(n_row >= 0 && n_col >= 0);
+                perimeter
+                    .entry(side_axis)
+                    .or_default()
+                    .push((n_row as usize, n_col as usize));
+            } else {
+                perimeter.entry(side_axis).or_default().push((row, col));
+            }
+        } else if map[n_row as usize][n_col as usize] != c {
+            if use_new {
+                perimeter
+                    .entry(side_axis)
+                    .or_default()
+                    .push((n_row as usize, n_col as usize));
+            } else {
+                perimeter.entry(side_axis).or_default().push((row, col));
+            }
+        } else if !visited[n_row as usize][n_col as usize] {
+            region_area += find_stats_b(map, visited, perimeter, n_row as usize, n_col as usize, c);
+        }
+    }
+
+    region_area
+}
+
+/// A straight piece of fence, `start` and `end` are the (row, col) of its first and last segment.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Side {
+    pub dir: Axis,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+impl Display for Side {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "Side: {:?}, [{}, {}] - [{}, {}]",
+            self.dir, self.start.0, self.start.1, self.end.0, self.end.1
+        )
+    }
+}
+
+fn find_region_sides(
+    map: &[Vec<char>],
+    perimeter: &mut HashMap<Axis, Vec<(usize, usize)>>,
+    region_c: char,
+) -> Vec<Side> {
+    let mut sides = Vec::new();
+    for (dir, perimeter_pieces) in perimeter.iter_mut() {
+        perimeter_pieces.sort_by_key(|&(r, c)| match dir {
+            Axis::Horizontal => -(c as isize),
+            Axis::Vertical => -(r as isize),
+        });
+        // println!("{dir:?}, {perimeter_pieces:?}");
+        while let Some((s_row, s_col)) = perimeter_pieces.pop() {
+            // println!("[{s_row}, {s_col}], {perimeter_pieces:?}");
+            let (side_anchor, mut side_e) = match dir {
+                Axis::Vertical => (s_col, s_row),
+                Axis::Horizontal => (s_row, s_col),
+            };
+
+            let off_a = match dir {
+                Axis::Vertical => (0, -1),
+                Axis::Horizontal => (-1, 0),
+            };
+
+            let char_a = {
+                let n_row = (s_row as isize) + off_a.0;
+                let n_col = (s_col as isize) + off_a.1;
+
+                if n_row < 0
+                    || n_row >= map.len() as isize
+                    || n_col < 0
+                    || n_col >= map[0].len() as isize
+                {
+                    false
+                } else {
+                    map[n_row as usize][n_col as usize] == region_c
+                }
+            };
+
+            let char_b = map
+                .get(s_row)
+                .and_then(|r| r.get(s_col))
+                .is_some_and(|&char_b| char_b == region_c);
+
+            for i in (0..perimeter_pieces.len()).rev() {
+                let (p_row, p_col) = perimeter_pieces[i];
+
+                if char_a != {
+                    let n_row = (p_row as isize) + off_a.0;
+                    let n_col = (p_col as isize) + off_a.1;
+
+                    if n_row < 0
+                        || n_row >= map.len() as isize
+                        || n_col < 0
+                        || n_col >= map[0].len() as isize
+                    {
+                        false
+                    } else {
+                        map[n_row as usize][n_col as usize] == region_c
+                    }
+                } {
+                    continue;
+                }
+
+                if char_b
+                    != map
+                        .get(p_row)
+                        .and_then(|r| r.get(p_col))
+                        .is_some_and(|&char_b| char_b == region_c)
+                {
+                    continue;
+                }
+
+                let (test_coord, match_coord) = match dir {
+                    Axis::Horizontal => (p_col, p_row),
+                    Axis::Vertical => (p_row, p_col),
+                };
+
+                if side_e + 1 == test_coord && side_anchor == match_coord {
+                    side_e = test_coord;
+                    perimeter_pieces.remove(i);
+                }
+            }
+
+            let (start, end) = match dir {
+                Axis::Vertical => ((s_row, side_anchor), (side_e, side_anchor)),
+                Axis::Horizontal => ((side_anchor, s_col), (side_anchor, side_e)),
+            };
+
+            sides.push(Side {
+                dir: *dir,
+                start,
+                end,
+            });
+        }
+    }
+
+    sides
+}
+
+/// A connected region of plants with its area and the sides of its fence.
+pub struct Region {
+    pub plant: char,
+    pub area: usize,
+    pub sides: Vec<Side>,
+}
+
+pub fn find_regions(map: &[Vec<char>]) -> Vec<Region> {
+    let num_cols = map[0].len();
+    let mut visited = vec![vec![false; num_cols]; map.len()];
+
+    let mut regions = Vec::new();
+
+    for row in 0..map.len() {
+        for col in 0..num_cols {
+            if visited[row][col] {
+                continue;
+            }
+
+            let mut perimeter = HashMap::new();
+
+            let area = find_stats_b(map, &mut visited, &mut perimeter, row, col, map[row][col]);
+
+            let mut sides = find_region_sides(map, &mut perimeter, map[row][col]);
+            // the perimeter is a HashMap, so the sides come out in random order
+            sides.sort();
+
+            regions.push(Region {
+                plant: map[row][col],
+                area,
+                sides,
+            });
+        }
+    }
+
+    regions
+}
+
+/// Lists every region followed by its sides, one per line.
+pub fn list_sides(map: &[Vec<char>]) -> String {
+    let mut listing = String::new();
+    for region in find_regions(map) {
+        listing.push_str(&format!(
+            "Region: {}: {} sides\n",
+            region.plant,
+            region.sides.len()
+        ));
+        for side in &region.sides {
+            listing.push_str(&format!("    {side}\n"));
+        }
+    }
+    listing
+}
+
+pub fn solve_b(map: &[Vec<char>]) -> usize {
+    find_regions(map)
+        .iter()
+        .map(|region| region.area * region.sides.len())
+        .sum()
+}
+
+pub fn parse(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    parse::grid(input)
+}
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+
+    type Input = Vec<Vec<char>>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(map: &Self::Input) -> String {
+        solve_a(map).to_string()
+    }
+
+    fn part2(map: &Self::Input) -> Option<String> {
+        Some(solve_b(map).to_string())
+    }
+}
@@ -0,0 +1,19 @@
+use day12::{list_sides, parse};
+
+#[test]
+fn example_sides() {
+    let map = parse(include_str!("../../example.txt")).unwrap();
+    insta::assert_snapshot!(list_sides(&map));
+}
+
+#[test]
+fn example2_sides() {
+    let map = parse(include_str!("../../example2.txt")).unwrap();
+    insta::assert_snapshot!(list_sides(&map));
+}
+
+#[test]
+fn example3_sides() {
+    let map = parse(include_str!("../../example3.txt")).unwrap();
+    insta::assert_snapshot!(list_sides(&map));
+}
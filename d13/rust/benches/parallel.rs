@@ -0,0 +1,39 @@
+//! Speedup of `solve_b` like `aoc/benches/parallel.rs` for the other days, which can't include day
+//! 13 because of z3: measured on a rayon thread pool with a single thread and on one with a thread
+//! per core.
+//!
+//! Run with `cargo bench --features parallel --bench parallel`.
+
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use day13::Day13;
+use rayon::ThreadPoolBuilder;
+use runner::Solution;
+
+fn parallel_benches(c: &mut Criterion) {
+    let (selected, input) =
+        runner::load_selection(concat!(env!("CARGO_MANIFEST_DIR"), "/.."), "input");
+    let input = Day13::parse_with(&runner::text::normalize(&input), &selected.params).unwrap();
+
+    let num_threads = std::thread::available_parallelism().map_or(1, usize::from);
+    let mut group = c.benchmark_group("day13");
+    group.sample_size(10);
+
+    let mut thread_counts = vec![1, num_threads];
+    thread_counts.dedup();
+    for threads in thread_counts {
+        let pool = ThreadPoolBuilder::new()
+            .num_threads(threads)
+            .build()
+            .unwrap();
+        group.bench_with_input(BenchmarkId::new("part2", threads), &threads, |b, _| {
+            b.iter(|| pool.install(|| black_box(Day13::part2(black_box(&input)))))
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parallel_benches);
+criterion_main!(benches);
@@ -0,0 +1,137 @@
+use regex::Regex;
+use runner::{overflow, par, parse, ParseError, Solution};
+use z3::ast::{Ast, Int};
+
+#[derive(Clone)]
+pub struct Machine {
+    button_a: [u64; 2],
+    button_b: [u64; 2],
+    prize: [u64; 2],
+}
+
+pub fn solve_a(machines: &[Machine]) -> u64 {
+    let mut sum = 0;
+    for machine in machines {
+        let mut solution: Option<u64> = None;
+        for num_b in 0..100 {
+            let b_x = num_b * machine.button_b[0];
+            let b_y = num_b * machine.button_b[1];
+            if b_x > machine.prize[0] || b_y > machine.prize[1] {
+                break;
+            }
+            for num_a in 0..100 {
+                let sum_x = b_x + num_a * machine.button_a[0];
+                let sum_y = b_y + num_a * machine.button_a[1];
+
+                if sum_x > machine.prize[0] || sum_y > machine.prize[1] {
+                    break;
+                }
+                if sum_x == machine.prize[0] && sum_y == machine.prize[1] {
+                    let presses = num_a * 3 + num_b;
+                    if let Some(sol_presses) = solution {
+                        if sol_presses > presses {
+                            solution = Some(presses);
+                        }
+                    } else {
+                        solution = Some(presses);
+                    }
+                    break;
+                }
+            }
+        }
+
+        if let Some(presses) = solution {
+            sum += presses;
+        }
+    }
+
+    sum
+}
+
+pub fn solve_b(mut machines: Vec<Machine>) -> u64 {
+    // adjust for measurement error
+    for machine in &mut machines {
+        for coord in &mut machine.prize {
+            *coord = overflow::add(13, "prize + 10000000000000", *coord, 10000000000000);
+        }
+    }
+
+    // z3 contexts can't be shared between threads, so every machine gets its own
+    par::sum(&machines, |machine| {
+        let ctx = z3::Context::new(&z3::Config::new());
+        let o = z3::Optimize::new(&ctx);
+        let num_a = Int::new_const(&ctx, "num_a");
+        let num_b = Int::new_const(&ctx, "num_b");
+        let prize_x_var = machine.button_a[0] * num_a.clone() + machine.button_b[0] * num_b.clone();
+        let prize_y_var = machine.button_a[1] * num_a.clone() + machine.button_b[1] * num_b.clone();
+        //  == machine.prize[0];
+        o.assert(&prize_x_var._eq(&Int::from_u64(&ctx, machine.prize[0])));
+        o.assert(&prize_y_var._eq(&Int::from_u64(&ctx, machine.prize[1])));
+        o.assert(&(num_a.ge(&Int::from_u64(&ctx, 0))));
+        o.assert(&(num_b.ge(&Int::from_u64(&ctx, 0))));
+        let token_num = (3u64 * num_a) + num_b;
+        o.minimize(&token_num);
+        match o.check(&[]) {
+            z3::SatResult::Unsat => 0,
+            z3::SatResult::Unknown => unreachable!(),
+            z3::SatResult::Sat => o
+                .get_model()
+                .unwrap()
+                .eval(&token_num, false)
+                .unwrap()
+                .as_u64()
+                .unwrap(),
+        }
+    })
+}
+
+pub fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+    let machine_re = Regex::new(
+        r"^Button A: X\+(\d+), Y\+(\d+)\s+Button B: X\+(\d+), Y\+(\d+)\s+Prize: X=(\d+), Y=(\d+)$",
+    )
+    .unwrap();
+
+    runner::text::sections(input)
+        .into_iter()
+        .enumerate()
+        .map(|(machine_idx, machine)| {
+            let Some(caps) = machine_re.captures(machine) else {
+                return Err(ParseError::new(format!(
+                    "machine {} doesn't look like two buttons and a prize",
+                    machine_idx + 1
+                )));
+            };
+            let button_a_x = parse::number(&caps[1])?;
+            let button_a_y = parse::number(&caps[2])?;
+            let button_b_x = parse::number(&caps[3])?;
+            let button_b_y = parse::number(&caps[4])?;
+            let prize_x = parse::number(&caps[5])?;
+            let prize_y = parse::number(&caps[6])?;
+            Ok(Machine {
+                button_a: [button_a_x, button_a_y],
+                button_b: [button_b_x, button_b_y],
+                prize: [prize_x, prize_y],
+            })
+        })
+        .collect()
+}
+
+pub struct Day13;
+
+impl Solution for Day13 {
+    const DAY: u8 = 13;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(machines: &Self::Input) -> String {
+        solve_a(machines).to_string()
+    }
+
+    fn part2(machines: &Self::Input) -> Option<String> {
+        Some(solve_b(machines.clone()).to_string())
+    }
+}
@@ -0,0 +1,18 @@
+use day13::Day13;
+use runner::{Params, Phase};
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+/// Like `sectioned_examples_accept_crlf_and_trailing_spaces` in `aoc/tests/examples.rs`, which
+/// can't include day 13 because of z3.
+#[test]
+fn example_accepts_crlf_and_trailing_spaces() {
+    let mut input: String = EXAMPLE
+        .lines()
+        .map(|line| format!("{line}  \r\n"))
+        .collect();
+    input.push_str("\r\n");
+
+    let answer = runner::solve_part::<Day13>(&input, &Params::default(), Phase::Part1).unwrap();
+    assert_eq!(answer.as_deref(), Some("480"));
+}
@@ -0,0 +1,85 @@
+//! Like `aoc/tests/parsers.rs` for day 13, which can't be part of it because of z3: `parse` has to
+//! turn any input into either the machines or an error, without panicking.
+
+use proptest::{prelude::*, sample::Index};
+
+const EXAMPLE: &str = include_str!("../../example.txt");
+
+#[derive(Clone, Debug)]
+enum Mutation {
+    Insert(Index, char),
+    Delete(Index),
+    Replace(Index, char),
+    Truncate(Index),
+}
+
+impl Mutation {
+    fn apply(&self, input: &str) -> String {
+        let mut chars: Vec<char> = input.chars().collect();
+        match *self {
+            Mutation::Insert(idx, c) => chars.insert(idx.index(chars.len() + 1), c),
+            Mutation::Delete(idx) if !chars.is_empty() => {
+                chars.remove(idx.index(chars.len()));
+            }
+            Mutation::Replace(idx, c) if !chars.is_empty() => {
+                let idx = idx.index(chars.len());
+                chars[idx] = c;
+            }
+            Mutation::Truncate(idx) => chars.truncate(idx.index(chars.len() + 1)),
+            _ => {}
+        }
+        chars.into_iter().collect()
+    }
+}
+
+/// Mostly digits and the characters around them, so the mutations stay close to the machines.
+fn machine_char() -> impl Strategy<Value = char> {
+    prop_oneof![
+        3 => proptest::char::range('0', '9'),
+        2 => proptest::sample::select(vec!['+', '-', '=', ',', ' ', '\n', '\r', 'X', 'Y']),
+        1 => any::<char>(),
+    ]
+}
+
+fn mutation() -> impl Strategy<Value = Mutation> {
+    prop_oneof![
+        (any::<Index>(), machine_char()).prop_map(|(idx, c)| Mutation::Insert(idx, c)),
+        any::<Index>().prop_map(Mutation::Delete),
+        (any::<Index>(), machine_char()).prop_map(|(idx, c)| Mutation::Replace(idx, c)),
+        any::<Index>().prop_map(Mutation::Truncate),
+    ]
+}
+
+proptest! {
+    #[test]
+    fn parser_accepts_arbitrary_strings(input in any::<String>()) {
+        let _ = day13::parse(&input);
+    }
+
+    #[test]
+    fn parser_accepts_printable_lines(input in "[ -~\n]{0,200}") {
+        let _ = day13::parse(&input);
+    }
+
+    #[test]
+    fn parser_accepts_mutated_examples(mutations in proptest::collection::vec(mutation(), 1..8)) {
+        let input = mutations
+            .iter()
+            .fold(EXAMPLE.to_string(), |input, mutation| mutation.apply(&input));
+        let _ = day13::parse(&runner::text::normalize(&input));
+    }
+
+    #[test]
+    fn parser_reads_every_machine(
+        machines in proptest::collection::vec(proptest::array::uniform6(0u64..100_000), 1..5),
+    ) {
+        let input: Vec<String> = machines
+            .iter()
+            .map(|[ax, ay, bx, by, px, py]| {
+                format!("Button A: X+{ax}, Y+{ay}\nButton B: X+{bx}, Y+{by}\nPrize: X={px}, Y={py}\n")
+            })
+            .collect();
+        let parsed = day13::parse(&input.join("\n")).unwrap();
+        prop_assert_eq!(parsed.len(), machines.len());
+    }
+}
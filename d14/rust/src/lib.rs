@@ -0,0 +1,195 @@
+use regex::Regex;
+use runner::{geometry::Pos, parse, Params, ParseError, Solution};
+
+#[derive(Debug, Clone)]
+pub struct Robot {
+    pos: Pos,
+    v: Pos,
+}
+
+pub fn solve_a(robots: &[Robot], width: u64, height: u64) -> u64 {
+    let x_middle = width / 2;
+    let y_middle = height / 2;
+    let mut quadrants = [0; 4];
+    for robot in robots {
+        // println!("{robot:?}");
+        let end_pos = (robot.pos + robot.v * 100).wrap(height as usize, width as usize);
+        let end_x = end_pos.col as u64;
+        let end_y = end_pos.row as u64;
+        // println!("[{end_x}, {end_y}]");
+        let x_quad = if end_x < x_middle {
+            0
+        } else if end_x > x_middle {
+            2
+        } else {
+            continue;
+        };
+
+        let y_quad = if end_y < y_middle {
+            0
+        } else if end_y > y_middle {
+            1
+        } else {
+            continue;
+        };
+
+        quadrants[x_quad + y_quad] += 1;
+    }
+
+    // println!("{quadrants:?}");
+
+    quadrants
+        .into_iter()
+        .reduce(|acc, elem| acc * elem)
+        .unwrap()
+}
+
+// fn iterate_pics(mut robots: Vec<Robot>, width: u64, height: u64) -> u64 {
+//     let mut input_buf = String::new();
+//     let mut iteration = 0;
+//     loop {
+//         input_buf.clear();
+//         stdin().read_line(&mut input_buf).unwrap();
+//         if input_buf.starts_with("exit") {
+//             return iteration;
+//         }
+
+//         iteration += 1;
+
+//         let mut map = vec![vec![0; width as usize]; height as usize];
+//         for robot in &mut robots {
+//             robot.pos[0] = (robot.pos[0] + robot.v[0] + width as i64) % width as i64;
+//             robot.pos[1] = (robot.pos[1] + robot.v[1] + width as i64) % width as i64;
+//             map[robot.pos[1] as usize][robot.pos[0] as usize] += 1;
+//         }
+
+//         for r in 0..(height as usize) {
+//             for c in 0..(width as usize) {
+//                 if map[r][c] == 0 {
+//                     print!(".");
+//                 } else  {
+//                     print!("{}", map[r][c]);
+//                 }
+//             }
+//             println!("");
+//         }
+
+//     }
+// }
+
+pub fn step(robots: &mut [Robot], width: u64, height: u64) {
+    for robot in robots {
+        robot.pos = (robot.pos + robot.v).wrap(height as usize, width as usize);
+    }
+}
+
+pub fn render(robots: &[Robot], width: u64, height: u64) -> String {
+    let mut map = vec![vec![0; width as usize]; height as usize];
+    for robot in robots {
+        map[robot.pos.row as usize][robot.pos.col as usize] += 1;
+    }
+
+    let mut frame = String::new();
+    for row in map {
+        for count in row {
+            if count == 0 {
+                frame.push('.');
+            } else {
+                frame.push_str(&count.to_string());
+            }
+        }
+        frame.push('\n');
+    }
+    frame
+}
+
+/// The robots only arrange into the christmas tree in a frame where no two of them share a tile.
+/// The positions repeat after `width * height` steps, so the search gives up after that.
+pub fn find_christmas_tree(
+    mut robots: Vec<Robot>,
+    width: u64,
+    height: u64,
+) -> Option<(u64, Vec<Robot>)> {
+    for iteration in 1..=(width * height) {
+        step(&mut robots, width, height);
+
+        let mut map = vec![vec![false; width as usize]; height as usize];
+        let is_distinct = robots.iter().all(|robot| {
+            let tile = &mut map[robot.pos.row as usize][robot.pos.col as usize];
+            !std::mem::replace(tile, true)
+        });
+
+        if is_distinct {
+            return Some((iteration, robots));
+        }
+    }
+
+    None
+}
+
+pub fn parse(input: &str) -> Result<Vec<Robot>, ParseError> {
+    let robot_re = Regex::new(r"^p=(\d+),(\d+) v=(-?\d+),(-?\d+)$").unwrap();
+
+    parse::lines(input, |line| {
+        let Some(cap) = robot_re.captures(line) else {
+            return Err(ParseError::new("expected `p=x,y v=x,y`"));
+        };
+        Ok(Robot {
+            pos: Pos::new(parse::number(&cap[2])?, parse::number(&cap[1])?),
+            v: Pos::new(parse::number(&cap[4])?, parse::number(&cap[3])?),
+        })
+    })
+}
+
+/// The robots together with the size of the room they move in, the example uses a smaller room.
+pub struct Room {
+    pub robots: Vec<Robot>,
+    pub width: u64,
+    pub height: u64,
+}
+
+pub struct Day14;
+
+impl Solution for Day14 {
+    const DAY: u8 = 14;
+
+    type Input = Room;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let robots = parse(input)?;
+        let width = params.get_or("width", 101);
+        let height = params.get_or("height", 103);
+        if width == 0 || height == 0 {
+            return Err(ParseError::new("the room needs a width and a height"));
+        }
+
+        if let Some(robot) = robots
+            .iter()
+            .find(|robot| robot.pos != robot.pos.wrap(height as usize, width as usize))
+        {
+            return Err(ParseError::new(format!(
+                "robot at p={},{} is outside of the {width}x{height} room",
+                robot.pos.col, robot.pos.row
+            )));
+        }
+
+        Ok(Room {
+            robots,
+            width,
+            height,
+        })
+    }
+
+    fn part1(room: &Self::Input) -> String {
+        solve_a(&room.robots, room.width, room.height).to_string()
+    }
+
+    fn part2(room: &Self::Input) -> Option<String> {
+        find_christmas_tree(room.robots.clone(), room.width, room.height)
+            .map(|(iteration, _)| iteration.to_string())
+    }
+}
@@ -0,0 +1,10 @@
+use day14::{parse, render, step};
+
+#[test]
+fn example_after_100_seconds() {
+    let mut robots = parse(include_str!("../../example.txt")).unwrap();
+    for _ in 0..100 {
+        step(&mut robots, 11, 7);
+    }
+    insta::assert_snapshot!(render(&robots, 11, 7));
+}
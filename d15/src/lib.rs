@@ -0,0 +1,235 @@
+use std::collections::HashSet;
+
+use runner::{geometry::Dir, parse, ParseError, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+enum Object {
+    Border,
+    Box,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Object2 {
+    Border,
+    BoxStart,
+    BoxEnd,
+}
+
+pub fn render_map(map: &[Vec<Option<Object2>>], robot_row: usize, robot_col: usize) -> String {
+    let mut rendered = String::new();
+    for (row_idx, row) in map.iter().enumerate() {
+        for (col_idx, col) in row.iter().enumerate() {
+            if row_idx == robot_row && col_idx == robot_col {
+                rendered.push('@');
+            } else {
+                rendered.push(match col {
+                    Some(obj) => match obj {
+                        Object2::Border => '#',
+                        Object2::BoxStart => '[',
+                        Object2::BoxEnd => ']',
+                    },
+                    None => '.',
+                });
+            }
+        }
+        rendered.push('\n');
+    }
+    rendered
+}
+
+pub fn print_map(map: &[Vec<Option<Object2>>], robot_row: usize, robot_col: usize) {
+    print!("{}", render_map(map, robot_row, robot_col));
+}
+
+/// The warehouse before the robot moves, surrounded by walls, and the moves of the robot.
+pub struct Warehouse {
+    map: Vec<Vec<Option<Object>>>,
+    robot_row: usize,
+    robot_col: usize,
+    instructions: Vec<Dir>,
+}
+
+pub fn parse(input: &str) -> Result<Warehouse, ParseError> {
+    let [map, instructions] = runner::text::sections(input)[..] else {
+        return Err(ParseError::new("expected the map and the moves"));
+    };
+
+    let mut robot = None;
+    let map = parse::grid_with(map, |row, col, c| match c {
+        '#' => Ok(Some(Object::Border)),
+        '.' => Ok(None),
+        'O' => Ok(Some(Object::Box)),
+        '@' if robot.is_none() => {
+            robot = Some((row, col));
+            Ok(None)
+        }
+        '@' => Err(ParseError::new("more than one robot")),
+        _ => Err(ParseError::unexpected_char(c)),
+    })?;
+    let Some((robot_row, robot_col)) = robot else {
+        return Err(ParseError::new("no robot in the map"));
+    };
+
+    parse::walled(&map, |obj| *obj == Some(Object::Border))?;
+
+    let instructions = instructions
+        .chars()
+        .filter(|c| !c.is_whitespace())
+        .map(|c| Dir::from_arrow(c).ok_or(ParseError::unexpected_char(c)))
+        .collect::<Result<_, _>>()?;
+
+    Ok(Warehouse {
+        map,
+        robot_row,
+        robot_col,
+        instructions,
+    })
+}
+
+pub fn solve_a(warehouse: &Warehouse) -> u64 {
+    let mut map = warehouse.map.clone();
+    let mut robot_row = warehouse.robot_row;
+    let mut robot_col = warehouse.robot_col;
+
+    'outer: for &dir in &warehouse.instructions {
+        let (mut n_row, mut n_col) = dir.apply(robot_row, robot_col);
+        let (next_robot_row, next_robot_col) = (n_row, n_col);
+
+        while let Some(obj) = map[n_row][n_col] {
+            match obj {
+                Object::Border => continue 'outer,
+                Object::Box => {
+                    (n_row, n_col) = dir.apply(n_row, n_col);
+                }
+            }
+        }
+
+        map[n_row][n_col] = Some(Object::Box);
+        map[next_robot_row][next_robot_col] = None;
+
+        robot_row = next_robot_row;
+        robot_col = next_robot_col;
+    }
+
+    map.into_iter()
+        .enumerate()
+        .map(|(row_idx, row)| {
+            row.into_iter()
+                .enumerate()
+                .filter(|&(_, col)| col.is_some_and(|obj| obj == Object::Box))
+                .map(|(col_idx, _)| 100 * row_idx + col_idx)
+                .sum::<usize>()
+        })
+        .sum::<usize>() as u64
+}
+
+/// Runs all moves in the widened warehouse and returns the final map and robot position.
+pub fn move_robot_b(warehouse: &Warehouse) -> (Vec<Vec<Option<Object2>>>, usize, usize) {
+    let mut map: Vec<Vec<Option<Object2>>> = warehouse
+        .map
+        .iter()
+        .map(|row| {
+            row.iter()
+                .flat_map(|obj| match obj {
+                    Some(Object::Border) => [Some(Object2::Border), Some(Object2::Border)],
+                    Some(Object::Box) => [Some(Object2::BoxStart), Some(Object2::BoxEnd)],
+                    None => [None, None],
+                })
+                .collect()
+        })
+        .collect();
+
+    let mut robot_row = warehouse.robot_row;
+    let mut robot_col = 2 * warehouse.robot_col;
+
+    'outer: for &dir in &warehouse.instructions {
+        // print_map(&map, robot_row, robot_col);
+        // println!("{dir:?}");
+        let (next_robot_row, next_robot_col) = dir.apply(robot_row, robot_col);
+        let mut check_stack = vec![(next_robot_row, next_robot_col, true)];
+
+        let mut visited = HashSet::new();
+        let mut box_moves = HashSet::new();
+
+        while let Some((n_row, n_col, check_other)) = check_stack.pop() {
+            if visited.contains(&(n_row, n_col)) {
+                continue;
+            }
+            visited.insert((n_row, n_col));
+            match map[n_row][n_col] {
+                Some(obj) => match obj {
+                    Object2::Border => continue 'outer,
+                    Object2::BoxStart => {
+                        box_moves.insert((n_row, n_col));
+                        let (next_row, next_col) = dir.apply(n_row, n_col);
+                        check_stack.push((next_row, next_col, true));
+                        if check_other {
+                            check_stack.push((n_row, n_col + 1, false));
+                        }
+                    }
+                    Object2::BoxEnd => {
+                        let (next_row, next_col) = dir.apply(n_row, n_col);
+                        check_stack.push((next_row, next_col, true));
+                        if check_other {
+                            check_stack.push((n_row, n_col - 1, false));
+                        }
+                    }
+                },
+                None => continue,
+            }
+        }
+
+        assert!(check_stack.is_empty());
+
+        for &(box_row, box_col) in &box_moves {
+            map[box_row][box_col] = None;
+            map[box_row][box_col + 1] = None;
+        }
+        for &(box_row, box_col) in &box_moves {
+            let (moved_row, moved_col) = dir.apply(box_row, box_col);
+            map[moved_row][moved_col] = Some(Object2::BoxStart);
+            map[moved_row][moved_col + 1] = Some(Object2::BoxEnd);
+        }
+        map[next_robot_row][next_robot_col] = None;
+
+        robot_row = next_robot_row;
+        robot_col = next_robot_col;
+    }
+
+    (map, robot_row, robot_col)
+}
+
+pub fn solve_b(warehouse: &Warehouse) -> u64 {
+    let (map, _, _) = move_robot_b(warehouse);
+
+    map.into_iter()
+        .enumerate()
+        .map(|(row_idx, row)| {
+            row.into_iter()
+                .enumerate()
+                .filter(|&(_, col)| col.is_some_and(|obj| obj == Object2::BoxStart))
+                .map(|(col_idx, _)| 100 * row_idx + col_idx)
+                .sum::<usize>()
+        })
+        .sum::<usize>() as u64
+}
+
+pub struct Day15;
+
+impl Solution for Day15 {
+    const DAY: u8 = 15;
+
+    type Input = Warehouse;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(warehouse: &Self::Input) -> String {
+        solve_a(warehouse).to_string()
+    }
+
+    fn part2(warehouse: &Self::Input) -> Option<String> {
+        Some(solve_b(warehouse).to_string())
+    }
+}
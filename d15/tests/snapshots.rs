@@ -0,0 +1,14 @@
+use day15::{move_robot_b, parse, render_map};
+
+#[test]
+fn example_final_warehouse() {
+    let (map, robot_row, robot_col) = move_robot_b(&parse(include_str!("../example.txt")).unwrap());
+    insta::assert_snapshot!(render_map(&map, robot_row, robot_col));
+}
+
+#[test]
+fn example2_final_warehouse() {
+    let (map, robot_row, robot_col) =
+        move_robot_b(&parse(include_str!("../example2.txt")).unwrap());
+    insta::assert_snapshot!(render_map(&map, robot_row, robot_col));
+}
@@ -0,0 +1,132 @@
+use std::collections::{HashMap, HashSet};
+
+use runner::{geometry::Dir, parse, ParseError, Solution};
+
+const COST_TURN: u64 = 1000;
+const COST_STEP: u64 = 1;
+
+pub fn solve(
+    walls: &[Vec<bool>],
+    start_row: usize,
+    start_col: usize,
+    end_row: usize,
+    end_col: usize,
+) -> (u64, u64) {
+    let mut queue = vec![(start_row, start_col, Dir::Right, 0, Vec::new())];
+
+    let mut visited = HashMap::new();
+    let mut min_cost_tiles = HashSet::new();
+    let mut min_cost = u64::MAX;
+
+    while let Some((check_row, check_col, check_dir, acc_cost, history)) = queue.pop() {
+        if min_cost < acc_cost {
+            continue;
+        }
+        if check_row == end_row && check_col == end_col {
+            if acc_cost < min_cost {
+                min_cost_tiles = HashSet::new();
+                min_cost = acc_cost;
+            }
+            if acc_cost == min_cost {
+                for history_tile in history {
+                    min_cost_tiles.insert(history_tile);
+                }
+            }
+            continue;
+        }
+
+        if let Some(&old_cost) = visited.get(&(check_row, check_col)) {
+            if old_cost < acc_cost {
+                continue;
+            }
+        }
+
+        visited
+            .entry((check_row, check_col))
+            .and_modify(|cost| *cost = acc_cost)
+            .or_insert(acc_cost);
+
+        let mut check_direction = |dir: Dir, extra_cost| {
+            let (n_row, n_col) = dir.apply(check_row, check_col);
+            if !walls[n_row][n_col] {
+                let mut history_clone = history.clone();
+                history_clone.push((n_row, n_col));
+                queue.push((n_row, n_col, dir, acc_cost + extra_cost, history_clone));
+            }
+        };
+
+        check_direction(check_dir, COST_STEP);
+        check_direction(check_dir.turn_right(), COST_TURN + COST_STEP);
+        check_direction(check_dir.turn_left(), COST_TURN + COST_STEP);
+    }
+
+    min_cost_tiles.insert((start_row, start_col));
+
+    (min_cost, min_cost_tiles.len() as u64)
+}
+
+pub struct Maze {
+    pub walls: Vec<Vec<bool>>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+pub fn parse(input: &str) -> Result<Maze, ParseError> {
+    let mut start = None;
+    let mut end = None;
+
+    let walls = parse::grid_with(input, |row_idx, col_idx, c| match c {
+        '#' => Ok(true),
+        'S' if start.is_none() => {
+            start = Some((row_idx, col_idx));
+            Ok(false)
+        }
+        'E' if end.is_none() => {
+            end = Some((row_idx, col_idx));
+            Ok(false)
+        }
+        '.' => Ok(false),
+        _ => Err(ParseError::unexpected_char(c)),
+    })?;
+    parse::walled(&walls, |&wall| wall)?;
+
+    Ok(Maze {
+        walls,
+        start: start.ok_or(ParseError::new("no start tile"))?,
+        end: end.ok_or(ParseError::new("no end tile"))?,
+    })
+}
+
+pub struct Day16;
+
+impl Solution for Day16 {
+    const DAY: u8 = 16;
+
+    type Input = Maze;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(maze: &Self::Input) -> String {
+        let (min_cost, _) = solve(
+            &maze.walls,
+            maze.start.0,
+            maze.start.1,
+            maze.end.0,
+            maze.end.1,
+        );
+        min_cost.to_string()
+    }
+
+    fn part2(maze: &Self::Input) -> Option<String> {
+        let (_, num_tiles) = solve(
+            &maze.walls,
+            maze.start.0,
+            maze.start.1,
+            maze.end.0,
+            maze.end.1,
+        );
+        Some(num_tiles.to_string())
+    }
+}
@@ -0,0 +1,225 @@
+use regex::Regex;
+use runner::{parse, ParseError, Solution};
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Instruction {
+    Adv(ComboOperand),
+    Bxl(u8),
+    Bst(ComboOperand),
+    Jnz(u8),
+    Bxc,
+    Out(ComboOperand),
+    Bdv(ComboOperand),
+    Cdv(ComboOperand),
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum ComboOperand {
+    Literal(u8),
+    A,
+    B,
+    C,
+}
+
+#[derive(Clone, Copy, Debug)]
+#[allow(non_snake_case)]
+pub struct State {
+    A: u64,
+    B: u64,
+    C: u64,
+}
+
+pub fn simulate(mut state: State, instrs: &[Instruction]) -> Vec<u8> {
+    let mut pc = 0;
+    let mut outputs = Vec::new();
+    loop {
+        let get_combo_val = |state: &State, combo: ComboOperand| match combo {
+            ComboOperand::Literal(literal) => literal as u64,
+            ComboOperand::A => state.A,
+            ComboOperand::B => state.B,
+            ComboOperand::C => state.C,
+        };
+        if let Some(&instr) = instrs.get(pc) {
+            // println!("{state:?}");
+            // println!("{pc}: {instr:?}");
+            match instr {
+                Instruction::Adv(combo_operand) => {
+                    state.A /= 2u64.pow(get_combo_val(&state, combo_operand).try_into().unwrap())
+                }
+                Instruction::Bxl(literal) => state.B ^= literal as u64,
+                Instruction::Bst(combo_operand) => {
+                    state.B = get_combo_val(&state, combo_operand) % 8
+                }
+                Instruction::Jnz(literal) => {
+                    if state.A != 0 {
+                        pc = literal as usize;
+                        continue;
+                    }
+                }
+                Instruction::Bxc => state.B ^= state.C,
+                Instruction::Out(combo_operand) => {
+                    outputs.push((get_combo_val(&state, combo_operand) % 8) as u8);
+                }
+                Instruction::Bdv(combo_operand) => {
+                    state.B = state.A
+                        / (2u64.pow(get_combo_val(&state, combo_operand).try_into().unwrap()))
+                }
+                Instruction::Cdv(combo_operand) => {
+                    state.C = state.A
+                        / (2u64.pow(get_combo_val(&state, combo_operand).try_into().unwrap()))
+                }
+            }
+            pc += 1;
+        } else {
+            break outputs;
+        }
+    }
+}
+
+pub fn find_initial_brute_force(instrs: &[Instruction], input_prog: &[u8]) -> State {
+    let mut a = 0;
+    loop {
+        if a % 1000 == 0 {
+            print!("\r{}", a);
+        }
+        let state = State { A: a, B: 0, C: 0 };
+        let output = simulate(state, instrs);
+        if output == input_prog {
+            println!();
+            break state;
+        }
+        a += 1;
+    }
+}
+
+/// The lowest initial value of register A that makes the program output itself, found by assuming
+/// the structure of the puzzle input. `None` for programs with another structure, like the examples.
+pub fn find_initial_smart(input_prog: &[u8]) -> Option<u64> {
+    let mut queue = vec![(0, 0)];
+    let mut min_initial = None;
+    while let Some((a, skip)) = queue.pop() {
+        // println!("{}", queue.len());
+        if let Some(min_initial) = min_initial {
+            if a >= min_initial {
+                continue;
+            }
+        }
+        if skip == input_prog.len() {
+            if let Some(min_initial) = min_initial.as_mut() {
+                *min_initial = a;
+            } else {
+                min_initial = Some(a);
+            }
+            continue;
+        }
+        let input_byte = input_prog.iter().rev().nth(skip).unwrap();
+        for b in 0..8 {
+            let b_prime = b ^ 6;
+            // println!("{b:#x}, {}, {}", b_prime ^ input_byte ^ 7, ((((a << 3) | b as u64) >> b_prime) % 8));
+            let new_a = (a << 3) | b as u64;
+            if (b_prime ^ input_byte ^ 7) == ((new_a >> b_prime) % 8) as u8 {
+                queue.push((new_a, skip + 1));
+            }
+        }
+    }
+    min_initial
+}
+
+pub struct Program {
+    pub state: State,
+    pub instrs: Vec<Instruction>,
+    pub input_prog: Vec<u8>,
+}
+
+pub fn parse(input: &str) -> Result<Program, ParseError> {
+    let [registers, program] = runner::text::sections(input)[..] else {
+        return Err(ParseError::new("expected the registers and the program"));
+    };
+
+    let register_re =
+        Regex::new(r"^Register A: (\d+)\s+Register B: (\d+)\s+Register C: (\d+)$").unwrap();
+
+    let state = {
+        let Some(cap) = register_re.captures(registers) else {
+            return Err(ParseError::new("expected the registers A, B and C"));
+        };
+        State {
+            A: parse::number(&cap[1])?,
+            B: parse::number(&cap[2])?,
+            C: parse::number(&cap[3])?,
+        }
+    };
+
+    let Some(program) = program.strip_prefix("Program: ") else {
+        return Err(ParseError::new(
+            "expected `Program: ` before the instructions",
+        ));
+    };
+    let input_prog: Vec<u8> = program
+        .split(',')
+        .map(|num| match parse::number(num.trim())? {
+            num @ 0..8 => Ok(num),
+            num => Err(ParseError::new(format!("{num} is not a 3-bit number"))),
+        })
+        .collect::<Result<_, _>>()?;
+    if !input_prog.len().is_multiple_of(2) {
+        return Err(ParseError::new("the last instruction has no operand"));
+    }
+
+    let instrs: Vec<Instruction> = input_prog
+        .chunks_exact(2)
+        .map(|instr| {
+            let (opcode, operand) = (instr[0], instr[1]);
+
+            let make_combo_op = || match operand {
+                0..=3 => Ok(ComboOperand::Literal(operand)),
+                4 => Ok(ComboOperand::A),
+                5 => Ok(ComboOperand::B),
+                6 => Ok(ComboOperand::C),
+                _ => Err(ParseError::new("the combo operand 7 is reserved")),
+            };
+
+            Ok(match opcode {
+                0 => Instruction::Adv(make_combo_op()?),
+                1 => Instruction::Bxl(operand),
+                2 => Instruction::Bst(make_combo_op()?),
+                3 => Instruction::Jnz(operand / 2),
+                4 => Instruction::Bxc,
+                5 => Instruction::Out(make_combo_op()?),
+                6 => Instruction::Bdv(make_combo_op()?),
+                7 => Instruction::Cdv(make_combo_op()?),
+                _ => unreachable!(),
+            })
+        })
+        .collect::<Result<_, _>>()?;
+
+    Ok(Program {
+        state,
+        instrs,
+        input_prog,
+    })
+}
+
+pub struct Day17;
+
+impl Solution for Day17 {
+    const DAY: u8 = 17;
+
+    type Input = Program;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(program: &Self::Input) -> String {
+        simulate(program.state, &program.instrs)
+            .into_iter()
+            .map(|o| o.to_string())
+            .collect::<Vec<_>>()
+            .join(",")
+    }
+
+    fn part2(program: &Self::Input) -> Option<String> {
+        find_initial_smart(&program.input_prog).map(|initial| initial.to_string())
+    }
+}
@@ -0,0 +1,14 @@
+use day17::Day17;
+use runner::Solution;
+
+#[test]
+fn example_output() {
+    let program = Day17::parse(include_str!("../example.txt")).unwrap();
+    insta::assert_snapshot!(Day17::part1(&program));
+}
+
+#[test]
+fn example2_output() {
+    let program = Day17::parse(include_str!("../example2.txt")).unwrap();
+    insta::assert_snapshot!(Day17::part1(&program));
+}
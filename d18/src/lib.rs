@@ -0,0 +1,148 @@
+use regex::Regex;
+use runner::{parse, Params, ParseError, Solution};
+
+pub fn find_path(map: &[Vec<bool>], map_size: usize, shortest: bool) -> Option<u64> {
+    let mut queue = vec![(0, 0, 0u64)];
+    let mut visited = vec![vec![None; map_size]; map_size];
+    while let Some((row, col, steps)) = queue.pop() {
+        if let Some(old_steps) = &mut visited[row][col] {
+            if *old_steps <= steps {
+                continue;
+            }
+            *old_steps = steps;
+        } else {
+            visited[row][col] = Some(steps);
+        }
+
+        if row == map_size - 1 && col == map_size - 1 {
+            if !shortest {
+                break;
+            } else {
+                continue;
+            }
+        }
+
+        if row > 0 && !map[row - 1][col] {
+            queue.push((row - 1, col, steps + 1));
+        }
+
+        if col > 0 && !map[row][col - 1] {
+            queue.push((row, col - 1, steps + 1));
+        }
+
+        if row + 1 < map_size && !map[row + 1][col] {
+            queue.push((row + 1, col, steps + 1));
+        }
+
+        if col + 1 < map_size && !map[row][col + 1] {
+            queue.push((row, col + 1, steps + 1));
+        }
+    }
+
+    visited[map_size - 1][map_size - 1]
+}
+
+pub fn parse(input: &str) -> Result<Vec<(usize, usize)>, ParseError> {
+    let coord_re = Regex::new(r"^(\d+),(\d+)$").unwrap();
+
+    parse::lines(input, |line| {
+        let Some(cap) = coord_re.captures(line) else {
+            return Err(ParseError::new("expected `x,y`"));
+        };
+        let row: usize = parse::number(&cap[1])?;
+        let col: usize = parse::number(&cap[2])?;
+        Ok((row, col))
+    })
+}
+
+fn build_map(
+    falling_bytes: &[(usize, usize)],
+    map_size: usize,
+    num_bytes: usize,
+) -> Vec<Vec<bool>> {
+    let mut map = vec![vec![false; map_size]; map_size];
+
+    for &(row, col) in falling_bytes.iter().take(num_bytes) {
+        map[row][col] = true;
+    }
+
+    map
+}
+
+pub fn solve_a(falling_bytes: &[(usize, usize)], map_size: usize, num_bytes: usize) -> u64 {
+    let map = build_map(falling_bytes, map_size, num_bytes);
+    find_path(&map, map_size, true).unwrap()
+}
+
+pub fn solve_b(
+    falling_bytes: &[(usize, usize)],
+    map_size: usize,
+    num_bytes: usize,
+) -> (usize, usize) {
+    let mut map = build_map(falling_bytes, map_size, num_bytes);
+
+    let mut next_byte = num_bytes;
+    loop {
+        if let Some(&(row, col)) = falling_bytes.get(next_byte) {
+            map[row][col] = true;
+        } else {
+            unreachable!();
+        }
+
+        if find_path(&map, map_size, false).is_none() {
+            break falling_bytes[next_byte];
+        }
+
+        next_byte += 1;
+    }
+}
+
+/// The falling bytes together with the size of the memory space and how many bytes fall in the
+/// first part, the example uses a smaller memory space.
+pub struct Memory {
+    pub falling_bytes: Vec<(usize, usize)>,
+    pub map_size: usize,
+    pub num_bytes: usize,
+}
+
+pub struct Day18;
+
+impl Solution for Day18 {
+    const DAY: u8 = 18;
+
+    type Input = Memory;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let falling_bytes = parse(input)?;
+        let map_size = params.get_or("map_size", 71) as usize;
+        if map_size == 0 {
+            return Err(ParseError::new("the memory needs a size"));
+        }
+        if let Some(byte_idx) = falling_bytes
+            .iter()
+            .position(|&(row, col)| row >= map_size || col >= map_size)
+        {
+            let message = format!("the byte falls outside of the {map_size}x{map_size} memory");
+            return Err(ParseError::new(message).in_line(byte_idx));
+        }
+
+        Ok(Memory {
+            falling_bytes,
+            map_size,
+            num_bytes: params.get_or("num_bytes", 1024) as usize,
+        })
+    }
+
+    fn part1(memory: &Self::Input) -> String {
+        solve_a(&memory.falling_bytes, memory.map_size, memory.num_bytes).to_string()
+    }
+
+    fn part2(memory: &Self::Input) -> Option<String> {
+        let (row, col) = solve_b(&memory.falling_bytes, memory.map_size, memory.num_bytes);
+        Some(format!("{row},{col}"))
+    }
+}
@@ -0,0 +1,210 @@
+use runner::{par, parse, Params, ParseError, Solution};
+
+fn find_shortest_path(
+    walls: &[Vec<bool>],
+    start_row: usize,
+    start_col: usize,
+    end_row: usize,
+    end_col: usize,
+) -> Vec<Vec<Option<u64>>> {
+    let num_rows = walls.len();
+    let num_cols = walls[0].len();
+    let mut queue = vec![(start_row, start_col, 0u64)];
+
+    let mut visited = vec![vec![None; num_rows]; num_cols];
+
+    while let Some((row, col, time)) = queue.pop() {
+        if walls[row][col] {
+            unreachable!("wall at {row} {col}")
+        }
+        if let Some(old_time) = visited[row][col].as_mut() {
+            if *old_time < time {
+                continue;
+            }
+            *old_time = time;
+        } else {
+            visited[row][col] = Some(time);
+        }
+
+        if row == end_row && col == end_col {
+            continue;
+        }
+
+        let mut push_queue = |q_row: usize, q_col: usize| {
+            if !walls[q_row][q_col] {
+                queue.push((q_row, q_col, time + 1));
+            }
+        };
+
+        if row > 0 {
+            // go UP
+            push_queue(row - 1, col);
+        }
+
+        if row + 1 < num_rows {
+            // go DOWN
+            push_queue(row + 1, col);
+        }
+
+        if col > 0 {
+            // go LEFT
+            push_queue(row, col - 1);
+        }
+
+        if col + 1 < num_cols {
+            // go RIGHT
+            push_queue(row, col + 1);
+        }
+    }
+
+    visited
+}
+
+pub fn find_all_cheats(
+    walls: &[Vec<bool>],
+    start_row: usize,
+    start_col: usize,
+    end_row: usize,
+    end_col: usize,
+    max_cheat_time: usize,
+    min_saving: u64,
+) -> u64 {
+    let num_rows = walls.len();
+    let num_cols = walls[0].len();
+    let time_taken = find_shortest_path(walls, start_row, start_col, end_row, end_col);
+
+    let regular_time = time_taken[end_row][end_col].unwrap();
+
+    par::sum(0..num_rows * num_cols, |cell| {
+        let (s_row, s_col) = (cell / num_cols, cell % num_cols);
+        if walls[s_row][s_col] {
+            return 0;
+        }
+
+        let mut counted_cheats = 0;
+        let start_time = time_taken[s_row][s_col].unwrap();
+        for row_off in (-(max_cheat_time as isize))..=(max_cheat_time as isize) {
+            for col_off in (-(max_cheat_time as isize - row_off.abs()))
+                ..=(max_cheat_time as isize - row_off.abs())
+            {
+                if row_off == 0 && col_off == 0 {
+                    continue;
+                }
+                let target_row = s_row as isize + row_off;
+                let target_col = s_col as isize + col_off;
+                if target_row < 0
+                    || target_row >= num_rows as isize
+                    || target_col < 0
+                    || target_col >= num_cols as isize
+                {
+                    continue;
+                }
+
+                let target_row = target_row as usize;
+                let target_col = target_col as usize;
+
+                if walls[target_row][target_col] {
+                    continue;
+                }
+
+                let cheat_time = row_off.abs() + col_off.abs();
+                let end_time = time_taken[end_row][end_col].unwrap()
+                    - time_taken[target_row][target_col].unwrap();
+
+                let total_time = start_time + cheat_time as u64 + end_time;
+
+                if total_time + min_saving <= regular_time {
+                    counted_cheats += 1;
+                }
+            }
+        }
+        counted_cheats
+    })
+}
+
+pub struct RaceTrack {
+    pub walls: Vec<Vec<bool>>,
+    pub start: (usize, usize),
+    pub end: (usize, usize),
+}
+
+pub fn parse(input: &str) -> Result<RaceTrack, ParseError> {
+    let mut start = None;
+    let mut end = None;
+
+    let walls = parse::grid_with(input, |row_idx, col_idx, c| match c {
+        '#' => Ok(true),
+        '.' => Ok(false),
+        'S' if start.is_none() => {
+            start = Some((row_idx, col_idx));
+            Ok(false)
+        }
+        'E' if end.is_none() => {
+            end = Some((row_idx, col_idx));
+            Ok(false)
+        }
+        _ => Err(ParseError::unexpected_char(c)),
+    })?;
+    parse::walled(&walls, |&wall| wall)?;
+
+    Ok(RaceTrack {
+        walls,
+        start: start.ok_or(ParseError::new("no start tile"))?,
+        end: end.ok_or(ParseError::new("no end tile"))?,
+    })
+}
+
+/// The race track together with the minimal saving in picoseconds of the cheats to count.
+pub struct Race {
+    pub track: RaceTrack,
+    pub min_saving: u64,
+}
+
+pub struct Day20;
+
+impl Solution for Day20 {
+    const DAY: u8 = 20;
+
+    type Input = Race;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        Self::parse_with(input, &Params::default())
+    }
+
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        Ok(Race {
+            track: parse(input)?,
+            min_saving: params.get_or("min_saving", 100),
+        })
+    }
+
+    fn part1(race: &Self::Input) -> String {
+        let track = &race.track;
+        find_all_cheats(
+            &track.walls,
+            track.start.0,
+            track.start.1,
+            track.end.0,
+            track.end.1,
+            2,
+            race.min_saving,
+        )
+        .to_string()
+    }
+
+    fn part2(race: &Self::Input) -> Option<String> {
+        let track = &race.track;
+        Some(
+            find_all_cheats(
+                &track.walls,
+                track.start.0,
+                track.start.1,
+                track.end.0,
+                track.end.1,
+                20,
+                race.min_saving,
+            )
+            .to_string(),
+        )
+    }
+}
@@ -0,0 +1,100 @@
+use std::collections::HashMap;
+
+use runner::{overflow, par, parse, ParseError, Solution};
+
+fn next_secret_number(mut secret: u64) -> u64 {
+    secret ^= overflow::wrapping_mul(22, "secret * 64", secret, 64) % 0x1000000;
+    secret ^= secret / 32;
+    (secret ^ overflow::wrapping_mul(22, "secret * 2048", secret, 2048)) % 0x1000000
+}
+
+pub fn parse(input: &str) -> Result<Vec<u64>, ParseError> {
+    parse::lines(input, parse::number)
+}
+
+pub fn solve_a(secret_nums: &[u64]) -> u64 {
+    par::sum(secret_nums, |&initial_secret| {
+        let mut secret = initial_secret;
+        for _ in 0..2000 {
+            secret = next_secret_number(secret);
+        }
+        secret
+    })
+}
+
+/// The bananas a single buyer sells for at the first occurrence of every sequence of changes.
+fn sequence_prices(initial_secret: u64) -> HashMap<[i8; 4], u64> {
+    let mut prices = HashMap::new();
+    let mut first;
+    let mut second = initial_secret;
+    let mut third = next_secret_number(second);
+    let mut fourth = next_secret_number(third);
+    let mut fifth = next_secret_number(fourth);
+
+    for _ in 0..(2000 - 4) {
+        first = second;
+        second = third;
+        third = fourth;
+        fourth = fifth;
+        fifth = next_secret_number(fifth);
+        let change_a = ((second % 10) as i8) - ((first % 10) as i8);
+        let change_b = ((third % 10) as i8) - ((second % 10) as i8);
+        let change_c = ((fourth % 10) as i8) - ((third % 10) as i8);
+        let change_d = ((fifth % 10) as i8) - ((fourth % 10) as i8);
+
+        let seq = [change_a, change_b, change_c, change_d];
+
+        prices.entry(seq).or_insert(fifth % 10);
+    }
+
+    prices
+}
+
+fn merge_sequence_sums(
+    mut lhs: HashMap<[i8; 4], u64>,
+    mut rhs: HashMap<[i8; 4], u64>,
+) -> HashMap<[i8; 4], u64> {
+    if lhs.len() < rhs.len() {
+        std::mem::swap(&mut lhs, &mut rhs);
+    }
+    for (seq, price) in rhs {
+        *lhs.entry(seq).or_insert(0) += price;
+    }
+    lhs
+}
+
+/// Returns the most bananas that can be bought and the sequence of changes to wait for. If
+/// several sequences are equally good, the smallest one is returned.
+pub fn solve_b(secret_nums: &[u64]) -> (u64, Option<[i8; 4]>) {
+    let sequence_sums = par::map_reduce(
+        secret_nums,
+        HashMap::new(),
+        |&initial_secret| sequence_prices(initial_secret),
+        merge_sequence_sums,
+    );
+
+    sequence_sums
+        .into_iter()
+        .max_by_key(|&(seq, sum)| (sum, std::cmp::Reverse(seq)))
+        .map_or((u64::MIN, None), |(seq, sum)| (sum, Some(seq)))
+}
+
+pub struct Day22;
+
+impl Solution for Day22 {
+    const DAY: u8 = 22;
+
+    type Input = Vec<u64>;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(secret_nums: &Self::Input) -> String {
+        solve_a(secret_nums).to_string()
+    }
+
+    fn part2(secret_nums: &Self::Input) -> Option<String> {
+        Some(solve_b(secret_nums).0.to_string())
+    }
+}
@@ -0,0 +1,436 @@
+use std::collections::{HashMap, HashSet};
+
+use runner::{parse, ParseError, Solution};
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum GateInput<'a> {
+    Wire(&'a str),
+    Value(bool),
+}
+
+impl<'a> GateInput<'a> {
+    fn get_write_unchecked(&self) -> &'a str {
+        match self {
+            GateInput::Wire(wire) => wire,
+            GateInput::Value(_) => panic!("get_write_unchecked on GateInput::Value"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum Op {
+    And,
+    Or,
+    Xor,
+}
+
+pub type Gate<'a> = (GateInput<'a>, GateInput<'a>, Op, &'a str);
+
+impl Op {
+    fn apply(self, a: bool, b: bool) -> bool {
+        match self {
+            Self::And => a && b,
+            Self::Or => a || b,
+            Self::Xor => a != b,
+        }
+    }
+}
+
+impl TryFrom<&str> for Op {
+    type Error = ();
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "AND" => Ok(Self::And),
+            "OR" => Ok(Self::Or),
+            "XOR" => Ok(Self::Xor),
+            _ => Err(()),
+        }
+    }
+}
+
+pub fn run_circuit<'a>(
+    mut gates: Vec<Gate<'a>>,
+    initial_values: &[(&'a str, bool)],
+    wire_usages: &HashMap<&'a str, HashSet<(usize, u8)>>,
+    outputs: &[&'a str],
+) -> u64 {
+    let mut work_queue = initial_values.to_vec();
+
+    let mut wires: HashMap<&str, bool> = HashMap::new();
+
+    while let Some((wire, value)) = work_queue.pop() {
+        wires.insert(wire, value);
+
+        if let Some(usages) = wire_usages.get(wire) {
+            for &(usage_idx, input_idx) in usages {
+                let gate = gates.get_mut(usage_idx).unwrap();
+                match input_idx {
+                    0 => gate.0 = GateInput::Value(value),
+                    1 => gate.1 = GateInput::Value(value),
+                    _ => unreachable!(),
+                }
+
+                if let (&GateInput::Value(a), &GateInput::Value(b)) = (&gate.0, &gate.1) {
+                    let result = gate.2.apply(a, b);
+                    wires.insert(gate.3, result);
+                    work_queue.push((gate.3, result));
+                }
+            }
+        }
+    }
+
+    let mut output_val = 0u64;
+    for output in outputs {
+        output_val = (output_val << 1) | (wires[output] as u64);
+    }
+
+    output_val
+}
+
+fn find_replacement<'a>(
+    known_input: &'a str,
+    replaced_input: &'a str,
+    op: Op,
+    gates: &[Gate<'a>],
+) -> Option<(&'a str, usize)> {
+    for (gate_idx, (input_a, input_b, gate_op, _)) in gates.iter().enumerate() {
+        if input_a == &GateInput::Wire(replaced_input)
+            || input_b == &GateInput::Wire(replaced_input)
+        {
+            continue;
+        }
+        let match_a = input_a == &GateInput::Wire(known_input);
+        let match_b = input_b == &GateInput::Wire(known_input);
+        if *gate_op == op && (match_a || match_b) {
+            if match_a {
+                return Some((input_b.get_write_unchecked(), gate_idx));
+            }
+            if match_b {
+                return Some((input_a.get_write_unchecked(), gate_idx));
+            }
+        }
+    }
+
+    None
+}
+
+fn swap<'a>(
+    gates: &mut [Gate<'a>],
+    wire_usages: &HashMap<&'a str, HashSet<(usize, u8)>>,
+    origin_map: &HashMap<&'a str, usize>,
+    a: &'a str,
+    b: &'a str,
+) {
+    // println!("swap: {a} <-> {b}");
+    if let Some(&origin) = origin_map.get(a) {
+        gates[origin].3 = b;
+    }
+    if let Some(&origin) = origin_map.get(b) {
+        gates[origin].3 = a;
+    }
+
+    if let Some(a_usages) = wire_usages.get(a) {
+        for &(gate_idx, input_idx) in a_usages {
+            let gate = &mut gates[gate_idx];
+            match input_idx {
+                0 => gate.0 = GateInput::Wire(b),
+                1 => gate.1 = GateInput::Wire(b),
+                _ => unreachable!(),
+            }
+        }
+    }
+    if let Some(b_usages) = wire_usages.get(b) {
+        for &(gate_idx, input_idx) in b_usages {
+            let gate = &mut gates[gate_idx];
+            match input_idx {
+                0 => gate.0 = GateInput::Wire(a),
+                1 => gate.1 = GateInput::Wire(a),
+                _ => unreachable!(),
+            }
+        }
+    }
+}
+
+fn find_actual_gate<'a>(
+    input_a: &'a str,
+    input_b: &'a str,
+    op: Op,
+    gates: &mut [Gate<'a>],
+    swaps: &mut Vec<&'a str>,
+    wire_usages: &HashMap<&'a str, HashSet<(usize, u8)>>,
+    origin_map: &HashMap<&'a str, usize>,
+) -> Option<usize> {
+    let mut gate_idx_opt = None;
+
+    if let Some(usages_a) = wire_usages.get(input_a) {
+        if let Some(usages_b) = wire_usages.get(input_b) {
+            for &(gate_idx, input_idx) in usages_a {
+                if usages_b.contains(&(gate_idx, 1 - input_idx)) {
+                    let gate = &gates[gate_idx];
+
+                    if gate.2 == op {
+                        gate_idx_opt = Some(gate_idx);
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    match gate_idx_opt {
+        None => {
+            let replacement_b = find_replacement(input_a, input_b, op, gates);
+            let replacement_a = find_replacement(input_b, input_a, op, gates);
+
+            match (replacement_a, replacement_b) {
+                (Some((replacement, gate_idx)), None) => {
+                    swaps.push(input_a);
+                    swaps.push(replacement);
+                    swap(gates, wire_usages, origin_map, input_a, replacement);
+                    Some(gate_idx)
+                }
+                (None, Some((replacement, gate_idx))) => {
+                    swaps.push(input_b);
+                    swaps.push(replacement);
+                    swap(gates, wire_usages, origin_map, input_b, replacement);
+
+                    Some(gate_idx)
+                }
+                // both inputs have been swapped, or either one could be, so the circuit is not an
+                // adder with single swapped outputs, like the examples
+                (Some(_), Some(_)) | (None, None) => None,
+            }
+        }
+        Some(gate_idx) => Some(gate_idx),
+    }
+}
+
+/// The sorted, comma separated outputs that have to be swapped to turn the circuit into an adder.
+/// `None` if it isn't an adder with swapped outputs, like the examples.
+pub fn find_swaps<'a>(
+    mut gates: Vec<Gate<'a>>,
+    mut initial_values: Vec<(&'a str, bool)>,
+    wire_usages: &HashMap<&'a str, HashSet<(usize, u8)>>,
+    origin_map: &HashMap<&'a str, usize>,
+) -> Option<String> {
+    // circuit must form an adder
+    // roll up circuit from the start
+
+    let mut swaps = Vec::new();
+
+    initial_values.sort_by(|(wire_a, _), (wire_b, _)| {
+        let num_a: u8 = wire_a[1..].parse().unwrap();
+        let num_b: u8 = wire_b[1..].parse().unwrap();
+        num_a.cmp(&num_b).then_with(|| {
+            wire_a
+                .chars()
+                .next()
+                .unwrap()
+                .cmp(&wire_b.chars().next().unwrap())
+        })
+    });
+
+    let mut n = 0;
+
+    let mut carry_wire_opt: Option<&str> = None;
+
+    loop {
+        if 2 * n >= initial_values.len() {
+            break;
+        }
+        let x_input = initial_values[2 * n].0;
+        let y_input = initial_values.get(2 * n + 1)?.0;
+
+        let first_xor_gate = find_actual_gate(
+            x_input,
+            y_input,
+            Op::Xor,
+            &mut gates,
+            &mut swaps,
+            wire_usages,
+            origin_map,
+        )?;
+        let first_and_gate = find_actual_gate(
+            x_input,
+            y_input,
+            Op::And,
+            &mut gates,
+            &mut swaps,
+            wire_usages,
+            origin_map,
+        )?;
+
+        let first_xor_output = gates[first_xor_gate].3;
+
+        match carry_wire_opt {
+            Some(carry_wire) => {
+                let second_xor_gate = find_actual_gate(
+                    carry_wire,
+                    first_xor_output,
+                    Op::Xor,
+                    &mut gates,
+                    &mut swaps,
+                    wire_usages,
+                    origin_map,
+                )?;
+
+                let second_and_gate = find_actual_gate(
+                    carry_wire,
+                    gates[first_xor_gate].3,
+                    Op::And,
+                    &mut gates,
+                    &mut swaps,
+                    wire_usages,
+                    origin_map,
+                )?;
+
+                let actual_output = gates[second_xor_gate].3;
+
+                let expected_output = format!("z{n:02}");
+                if actual_output != expected_output {
+                    let expected_output_with_correct_lifetime =
+                        origin_map.get_key_value(expected_output.as_str())?.0;
+                    swap(
+                        &mut gates,
+                        wire_usages,
+                        origin_map,
+                        actual_output,
+                        expected_output_with_correct_lifetime,
+                    );
+                    swaps.push(actual_output);
+                    swaps.push(expected_output_with_correct_lifetime);
+                }
+
+                let second_and_output = gates[second_and_gate].3;
+                let first_and_output = gates[first_and_gate].3;
+
+                let or_gate = find_actual_gate(
+                    first_and_output,
+                    second_and_output,
+                    Op::Or,
+                    &mut gates,
+                    &mut swaps,
+                    wire_usages,
+                    origin_map,
+                )?;
+
+                carry_wire_opt = Some(gates[or_gate].3);
+            }
+            None => {
+                let first_and_output = gates[first_and_gate].3;
+                if first_xor_output != "z00" {
+                    return None;
+                }
+                carry_wire_opt = Some(first_and_output);
+            }
+        }
+
+        n += 1;
+    }
+
+    swaps.sort();
+    Some(swaps.join(","))
+}
+
+pub struct Circuit<'a> {
+    pub gates: Vec<Gate<'a>>,
+    pub initials: Vec<(&'a str, bool)>,
+    pub wire_usages: HashMap<&'a str, HashSet<(usize, u8)>>,
+    pub origin_map: HashMap<&'a str, usize>,
+    pub outputs: Vec<&'a str>,
+}
+
+pub fn parse(input: &str) -> Result<Circuit<'_>, ParseError> {
+    let mut outputs = Vec::new();
+    let mut wire_usages: HashMap<&str, HashSet<(usize, u8)>> = HashMap::new();
+    let mut origin_map: HashMap<&str, usize> = HashMap::new();
+    let mut gates = Vec::new();
+
+    let [initials_str, gates_str] = runner::text::sections(input)[..] else {
+        return Err(ParseError::new("expected the initial values and the gates"));
+    };
+
+    let initials: Vec<(&str, bool)> = parse::lines(initials_str, |line| {
+        let Some((wire, value)) = line.split_once(':') else {
+            return Err(ParseError::new("expected `wire: value`"));
+        };
+        let value = match value.trim() {
+            "0" => false,
+            "1" => true,
+            value => return Err(ParseError::new(format!("{value:?} is not a bit"))),
+        };
+        Ok((wire, value))
+    })?;
+
+    let gate_lines = parse::lines(gates_str, |line| {
+        let splits: Vec<&str> = line.split_ascii_whitespace().collect();
+        let [a, op, b, "->", output] = splits[..] else {
+            return Err(ParseError::new("expected `a OP b -> output`"));
+        };
+        let op: Op = op
+            .try_into()
+            .map_err(|()| ParseError::new(format!("unknown gate {op:?}")))?;
+        Ok((a, op, b, output))
+    })?;
+
+    for (a, op, b, output) in gate_lines {
+        wire_usages.entry(a).or_default().insert((gates.len(), 0));
+        wire_usages.entry(b).or_default().insert((gates.len(), 1));
+
+        origin_map.insert(output, gates.len());
+
+        gates.push((GateInput::Wire(a), GateInput::Wire(b), op, output));
+
+        if output.starts_with('z') {
+            outputs.push(output);
+        }
+    }
+
+    outputs.sort();
+    outputs.reverse();
+
+    Ok(Circuit {
+        gates,
+        initials,
+        wire_usages,
+        origin_map,
+        outputs,
+    })
+}
+
+pub struct Day24;
+
+// the circuit borrows its wire names from the input, so both parts parse it themselves, after it
+// was checked once here
+impl Solution for Day24 {
+    const DAY: u8 = 24;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)?;
+        Ok(input.to_string())
+    }
+
+    fn part1(input: &Self::Input) -> String {
+        let circuit = parse(input).expect("checked by Day24::parse");
+        run_circuit(
+            circuit.gates,
+            &circuit.initials,
+            &circuit.wire_usages,
+            &circuit.outputs,
+        )
+        .to_string()
+    }
+
+    fn part2(input: &Self::Input) -> Option<String> {
+        let circuit = parse(input).expect("checked by Day24::parse");
+        find_swaps(
+            circuit.gates,
+            circuit.initials,
+            &circuit.wire_usages,
+            &circuit.origin_map,
+        )
+    }
+}
@@ -0,0 +1,94 @@
+use runner::{parse, ParseError, Solution};
+
+pub struct Schematics {
+    pub keys: Vec<[usize; 5]>,
+    pub locks: Vec<[usize; 5]>,
+}
+
+pub fn parse(input: &str) -> Result<Schematics, ParseError> {
+    let mut keys = Vec::new();
+    let mut locks = Vec::new();
+
+    for (schematic_idx, schematic) in runner::text::sections(input).into_iter().enumerate() {
+        let lines = parse::grid_with(schematic, |_, _, c| match c {
+            '#' | '.' => Ok(c),
+            _ => Err(ParseError::unexpected_char(c)),
+        })?;
+
+        if lines.len() != 7 || lines[0].len() != 5 {
+            return Err(ParseError::new(format!(
+                "schematic {} is not 5 columns wide and 7 rows high",
+                schematic_idx + 1
+            )));
+        }
+
+        let search_char = lines[0][0];
+        let is_lock = search_char == '#';
+
+        let heights: [usize; 5] = std::array::from_fn(|col| {
+            let mut height = 5;
+            for (row, line) in lines.iter().enumerate().take(6).skip(1) {
+                if line[col] != search_char {
+                    height = row - 1;
+                    break;
+                }
+            }
+
+            if is_lock {
+                height
+            } else {
+                5 - height
+            }
+        });
+
+        if is_lock {
+            locks.push(heights);
+        } else {
+            keys.push(heights);
+        }
+    }
+
+    Ok(Schematics { keys, locks })
+}
+
+pub fn solve_a(keys: &[[usize; 5]], locks: &[[usize; 5]]) -> u64 {
+    let mut valid_pairs = 0;
+
+    for key in keys {
+        for lock in locks {
+            let mut is_valid = true;
+            for i in 0..5 {
+                if key[i] + lock[i] > 5 {
+                    is_valid = false;
+                    break;
+                }
+            }
+
+            if is_valid {
+                valid_pairs += 1;
+            }
+        }
+    }
+
+    valid_pairs
+}
+
+pub struct Day25;
+
+impl Solution for Day25 {
+    const DAY: u8 = 25;
+
+    type Input = Schematics;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError> {
+        parse(input)
+    }
+
+    fn part1(schematics: &Self::Input) -> String {
+        solve_a(&schematics.keys, &schematics.locks).to_string()
+    }
+
+    fn part2(_: &Self::Input) -> Option<String> {
+        None
+    }
+}
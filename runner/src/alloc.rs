@@ -0,0 +1,142 @@
+use std::{
+    alloc::{GlobalAlloc, Layout, System},
+    sync::atomic::{AtomicI64, AtomicU64, Ordering},
+};
+
+/// Global allocator that forwards to [`System`] and counts every allocation.
+///
+/// Normally the counters are thread local, so days that are solved concurrently on different
+/// threads do not show up in each other's numbers. With the `parallel` feature the loops of a phase
+/// run on the rayon worker threads, so the counters are shared by all threads instead and a phase
+/// counts the allocations of the whole process while it runs.
+pub struct CountingAlloc;
+
+struct Counters {
+    allocations: AtomicU64,
+    bytes: AtomicU64,
+    live: AtomicI64,
+    peak: AtomicI64,
+}
+
+impl Counters {
+    const fn new() -> Self {
+        Self {
+            allocations: AtomicU64::new(0),
+            bytes: AtomicU64::new(0),
+            live: AtomicI64::new(0),
+            peak: AtomicI64::new(0),
+        }
+    }
+}
+
+#[cfg(not(feature = "parallel"))]
+thread_local! {
+    static COUNTERS: Counters = const { Counters::new() };
+}
+
+#[cfg(feature = "parallel")]
+static COUNTERS: Counters = Counters::new();
+
+/// Runs `f` with the counters of the current thread, or the shared ones with the `parallel`
+/// feature. Returns `None` while the thread local counters are being destroyed.
+fn with_counters<T>(f: impl FnOnce(&Counters) -> T) -> Option<T> {
+    #[cfg(not(feature = "parallel"))]
+    return COUNTERS.try_with(f).ok();
+
+    #[cfg(feature = "parallel")]
+    return Some(f(&COUNTERS));
+}
+
+fn record_alloc(size: usize) {
+    with_counters(|counters| {
+        counters.allocations.fetch_add(1, Ordering::Relaxed);
+        counters.bytes.fetch_add(size as u64, Ordering::Relaxed);
+        let live = counters.live.fetch_add(size as i64, Ordering::Relaxed) + size as i64;
+        counters.peak.fetch_max(live, Ordering::Relaxed);
+    });
+}
+
+fn record_dealloc(size: usize) {
+    with_counters(|counters| counters.live.fetch_sub(size as i64, Ordering::Relaxed));
+}
+
+unsafe impl GlobalAlloc for CountingAlloc {
+    unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+        let ptr = System.alloc_zeroed(layout);
+        if !ptr.is_null() {
+            record_alloc(layout.size());
+        }
+        ptr
+    }
+
+    unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+        System.dealloc(ptr, layout);
+        record_dealloc(layout.size());
+    }
+
+    // a realloc counts as a fresh allocation of the new size, like most heap profilers do
+    unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+        let new_ptr = System.realloc(ptr, layout, new_size);
+        if !new_ptr.is_null() {
+            record_dealloc(layout.size());
+            record_alloc(new_size);
+        }
+        new_ptr
+    }
+}
+
+/// Heap usage of a single phase, as seen by [`CountingAlloc`].
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct AllocStats {
+    /// Number of allocations (including reallocations).
+    pub allocations: u64,
+    /// Total number of bytes requested.
+    pub bytes: u64,
+    /// Highest amount of live heap memory above the level at the start of the phase.
+    pub peak: u64,
+}
+
+/// Snapshot of the counters, taken at the start of a phase. Scopes can be nested,
+/// the peak of an inner scope also counts towards the outer one.
+pub(crate) struct AllocScope {
+    allocations: u64,
+    bytes: u64,
+    live: i64,
+    /// The peak of the enclosing scope so far, restored by [`AllocScope::finish`].
+    outer_peak: i64,
+}
+
+impl AllocScope {
+    pub(crate) fn start() -> Self {
+        with_counters(|counters| {
+            let live = counters.live.load(Ordering::Relaxed);
+            Self {
+                allocations: counters.allocations.load(Ordering::Relaxed),
+                bytes: counters.bytes.load(Ordering::Relaxed),
+                live,
+                outer_peak: counters.peak.swap(live, Ordering::Relaxed),
+            }
+        })
+        .expect("the allocation counters are alive while measuring")
+    }
+
+    pub(crate) fn finish(self) -> AllocStats {
+        with_counters(|counters| {
+            let peak = counters.peak.fetch_max(self.outer_peak, Ordering::Relaxed);
+            AllocStats {
+                allocations: counters.allocations.load(Ordering::Relaxed) - self.allocations,
+                bytes: counters.bytes.load(Ordering::Relaxed) - self.bytes,
+                peak: (peak - self.live).max(0) as u64,
+            }
+        })
+        .expect("the allocation counters are alive while measuring")
+    }
+}
@@ -0,0 +1,275 @@
+//! Cache for the answers, so rerunning slow days is instant.
+//!
+//! An entry is keyed by the day, the part, the input, its parameters (like the grid size of day
+//! 14), the solution type and a hash of the running executable, so any rebuild of the solvers
+//! invalidates all entries. Every entry is a file containing the answer.
+//! Answers are never empty, so an empty entry stands for a part without answer (day 25).
+
+use std::{
+    any::type_name,
+    io,
+    path::{Path, PathBuf},
+};
+
+use crate::{measure, text, Params, ParseError, Phase, PhaseReport, Report, Solution};
+
+pub struct Cache {
+    dir: PathBuf,
+    build_hash: blake3::Hash,
+    fresh: bool,
+}
+
+impl Cache {
+    /// Opens the cache in `dir`. With `fresh` all answers are recomputed, but still written back.
+    pub fn new(dir: impl Into<PathBuf>, fresh: bool) -> io::Result<Self> {
+        let exe = std::fs::read(std::env::current_exe()?)?;
+
+        Ok(Self {
+            dir: dir.into(),
+            build_hash: blake3::hash(&exe),
+            fresh,
+        })
+    }
+
+    fn entry_path<S: Solution>(
+        &self,
+        phase: Phase,
+        input_hash: &blake3::Hash,
+        params: &Params,
+    ) -> PathBuf {
+        let mut hasher = blake3::Hasher::new();
+        hasher.update(&[S::DAY]);
+        hasher.update(phase.to_string().as_bytes());
+        hasher.update(input_hash.as_bytes());
+        for (name, value) in params.iter() {
+            hasher.update(name.as_bytes());
+            hasher.update(&value.to_le_bytes());
+        }
+        hasher.update(type_name::<S>().as_bytes());
+        hasher.update(self.build_hash.as_bytes());
+
+        self.dir
+            .join(format!("d{:02}", S::DAY))
+            .join(hasher.finalize().to_hex().as_str())
+    }
+
+    fn get(&self, path: &Path) -> Option<Option<String>> {
+        if self.fresh {
+            return None;
+        }
+
+        let answer = std::fs::read_to_string(path).ok()?;
+        Some((!answer.is_empty()).then_some(answer))
+    }
+
+    fn put(&self, path: &PathBuf, answer: Option<&str>) {
+        // a cache that cannot be written only costs time, so errors are ignored
+        if let Some(dir) = path.parent() {
+            let _ = std::fs::create_dir_all(dir);
+        }
+        let _ = std::fs::write(path, answer.unwrap_or_default());
+    }
+}
+
+/// Like [`crate::run`], but takes the answers from `cache` if possible. Parsing is skipped if
+/// both parts are cached.
+pub fn run_cached<S: Solution>(
+    input: &str,
+    params: &Params,
+    cache: &Cache,
+) -> Result<Report, ParseError> {
+    let input = &text::normalize(input);
+    let input_hash = blake3::hash(input.as_bytes());
+    let part1_path = cache.entry_path::<S>(Phase::Part1, &input_hash, params);
+    let part2_path = cache.entry_path::<S>(Phase::Part2, &input_hash, params);
+
+    let cached_part1 = cache.get(&part1_path).flatten();
+    let cached_part2 = cache.get(&part2_path);
+
+    if let (Some(part1), Some(part2)) = (&cached_part1, &cached_part2) {
+        return Ok(Report {
+            day: S::DAY,
+            part1: part1.clone(),
+            part2: part2.clone(),
+            phases: [Phase::Parse, Phase::Part1, Phase::Part2].map(PhaseReport::cached),
+        });
+    }
+
+    let (parsed, parse) = measure(Phase::Parse, || S::parse_with(input, params));
+    let parsed = parsed?;
+
+    let (part1, part1_report) = match cached_part1 {
+        Some(part1) => (part1, PhaseReport::cached(Phase::Part1)),
+        None => {
+            let (part1, report) = measure(Phase::Part1, || S::part1(&parsed));
+            cache.put(&part1_path, Some(&part1));
+            (part1, report)
+        }
+    };
+
+    let (part2, part2_report) = match cached_part2 {
+        Some(part2) => (part2, PhaseReport::cached(Phase::Part2)),
+        None => {
+            let (part2, report) = measure(Phase::Part2, || S::part2(&parsed));
+            cache.put(&part2_path, part2.as_deref());
+            (part2, report)
+        }
+    };
+
+    Ok(Report {
+        day: S::DAY,
+        part1,
+        part2,
+        phases: [parse, part1_report, part2_report],
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Counts the lines of the input, the second part is the last line of inputs with more than
+    /// one.
+    struct Lines;
+
+    impl Solution for Lines {
+        const DAY: u8 = 1;
+
+        type Input = Vec<String>;
+
+        fn parse(input: &str) -> Result<Self::Input, ParseError> {
+            Ok(input.lines().map(str::to_string).collect())
+        }
+
+        fn part1(lines: &Self::Input) -> String {
+            lines.len().to_string()
+        }
+
+        fn part2(lines: &Self::Input) -> Option<String> {
+            lines[1..].last().cloned()
+        }
+    }
+
+    fn cache(dir: &Path, build: &str, fresh: bool) -> Cache {
+        Cache {
+            dir: dir.to_path_buf(),
+            build_hash: blake3::hash(build.as_bytes()),
+            fresh,
+        }
+    }
+
+    fn params(toml: &str) -> Params {
+        toml::from_str(toml).unwrap()
+    }
+
+    fn cached(report: &Report) -> [bool; 3] {
+        report.phases.map(|phase| phase.cached)
+    }
+
+    #[test]
+    fn hit() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), "build", false);
+
+        let first = run_cached::<Lines>("a\nb\n", &Params::default(), &cache).unwrap();
+        assert_eq!(cached(&first), [false; 3]);
+        let second = run_cached::<Lines>("a\nb\n", &Params::default(), &cache).unwrap();
+        assert_eq!(cached(&second), [true; 3]);
+        assert_eq!((second.part1, second.part2), ("2".into(), Some("b".into())));
+    }
+
+    #[test]
+    fn hit_after_normalizing() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), "build", false);
+
+        run_cached::<Lines>("a\nb\n", &Params::default(), &cache).unwrap();
+        let report = run_cached::<Lines>("a \r\nb\r\n\r\n", &Params::default(), &cache).unwrap();
+        assert_eq!(cached(&report), [true; 3]);
+    }
+
+    #[test]
+    fn miss_for_other_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), "build", false);
+
+        run_cached::<Lines>("a\nb\n", &Params::default(), &cache).unwrap();
+        let report = run_cached::<Lines>("a\nc\n", &Params::default(), &cache).unwrap();
+        assert_eq!(cached(&report), [false; 3]);
+        assert_eq!(report.part2.as_deref(), Some("c"));
+    }
+
+    #[test]
+    fn miss_for_other_params() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), "build", false);
+
+        run_cached::<Lines>("a\nb\n", &params("width = 11"), &cache).unwrap();
+        let report = run_cached::<Lines>("a\nb\n", &params("width = 101"), &cache).unwrap();
+        assert_eq!(cached(&report), [false; 3]);
+        let report = run_cached::<Lines>("a\nb\n", &Params::default(), &cache).unwrap();
+        assert_eq!(cached(&report), [false; 3]);
+    }
+
+    #[test]
+    fn miss_for_other_build() {
+        let dir = tempfile::tempdir().unwrap();
+
+        run_cached::<Lines>(
+            "a\nb\n",
+            &Params::default(),
+            &cache(dir.path(), "build", false),
+        )
+        .unwrap();
+        let report = run_cached::<Lines>(
+            "a\nb\n",
+            &Params::default(),
+            &cache(dir.path(), "rebuild", false),
+        )
+        .unwrap();
+        assert_eq!(cached(&report), [false; 3]);
+    }
+
+    #[test]
+    fn fresh_solves_again_and_writes_back() {
+        let dir = tempfile::tempdir().unwrap();
+
+        run_cached::<Lines>(
+            "a\nb\n",
+            &Params::default(),
+            &cache(dir.path(), "build", false),
+        )
+        .unwrap();
+        let fresh = cache(dir.path(), "build", true);
+        let report = run_cached::<Lines>("a\nc\n", &Params::default(), &fresh).unwrap();
+        assert_eq!(cached(&report), [false; 3]);
+        let report = run_cached::<Lines>("a\nc\n", &Params::default(), &fresh).unwrap();
+        assert_eq!(cached(&report), [false; 3]);
+
+        let report = run_cached::<Lines>(
+            "a\nc\n",
+            &Params::default(),
+            &cache(dir.path(), "build", false),
+        )
+        .unwrap();
+        assert_eq!(cached(&report), [true; 3]);
+    }
+
+    #[test]
+    fn part_without_answer() {
+        let dir = tempfile::tempdir().unwrap();
+        let cache = cache(dir.path(), "build", false);
+
+        let first = run_cached::<Lines>("a\n", &Params::default(), &cache).unwrap();
+        assert_eq!(first.part2, None);
+
+        let input_hash = blake3::hash(b"a\n");
+        let path = cache.entry_path::<Lines>(Phase::Part2, &input_hash, &Params::default());
+        assert_eq!(std::fs::read_to_string(&path).unwrap(), "");
+        assert_eq!(cache.get(&path), Some(None));
+
+        let second = run_cached::<Lines>("a\n", &Params::default(), &cache).unwrap();
+        assert_eq!(cached(&second), [true; 3]);
+        assert_eq!((second.part1, second.part2), ("1".into(), None));
+    }
+}
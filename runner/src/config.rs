@@ -0,0 +1,118 @@
+//! The per-day `aoc.toml`, which lists the inputs of a day with their parameters and expected
+//! answers:
+//!
+//! ```toml
+//! [[inputs]]
+//! name = "example"
+//! file = "example.txt"
+//! params = { width = 11, height = 7 }
+//! part1 = "12"
+//! ```
+//!
+//! Files are relative to the day directory. A day without `aoc.toml` only has its `input.txt`.
+
+use std::{
+    collections::BTreeMap,
+    io,
+    path::{Path, PathBuf},
+};
+
+use serde::Deserialize;
+
+use crate::Phase;
+
+/// Parameters of an input that are not part of the puzzle text, like the grid size of day 14.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash, Deserialize)]
+#[serde(transparent)]
+pub struct Params(BTreeMap<String, u64>);
+
+impl Params {
+    pub fn get_or(&self, name: &str, default: u64) -> u64 {
+        self.0.get(name).copied().unwrap_or(default)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, u64)> {
+        self.0.iter().map(|(name, &value)| (name.as_str(), value))
+    }
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct InputConfig {
+    pub name: String,
+    pub file: PathBuf,
+    #[serde(default)]
+    pub params: Params,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+#[derive(Clone, Debug, Deserialize)]
+pub struct DayConfig {
+    #[serde(default)]
+    pub inputs: Vec<InputConfig>,
+}
+
+impl DayConfig {
+    pub const FILE_NAME: &str = "aoc.toml";
+
+    /// Loads the `aoc.toml` of `day_dir`. The file paths of the inputs are resolved against
+    /// `day_dir`, except for `-` which stands for stdin.
+    pub fn load(day_dir: &Path) -> io::Result<Self> {
+        let mut config = match std::fs::read_to_string(day_dir.join(Self::FILE_NAME)) {
+            Ok(content) => toml::from_str(&content)
+                .map_err(|err| io::Error::new(io::ErrorKind::InvalidData, err))?,
+            Err(err) if err.kind() == io::ErrorKind::NotFound => DayConfig {
+                inputs: vec![InputConfig::from_path("input", "input.txt")],
+            },
+            Err(err) => return Err(err),
+        };
+
+        for input in &mut config.inputs {
+            if input.file != Path::new("-") {
+                input.file = day_dir.join(&input.file);
+            }
+        }
+
+        Ok(config)
+    }
+
+    pub fn input(&self, name: &str) -> Option<&InputConfig> {
+        self.inputs.iter().find(|input| input.name == name)
+    }
+
+    /// Picks the input named `selection`, or treats `selection` as a path (or `-`) to an input
+    /// without parameters and expected answers if there is no input of that name.
+    pub fn select(&self, selection: &str) -> InputConfig {
+        self.input(selection)
+            .cloned()
+            .unwrap_or_else(|| InputConfig::from_path(selection, selection))
+    }
+}
+
+impl InputConfig {
+    fn from_path(name: &str, file: impl Into<PathBuf>) -> Self {
+        Self {
+            name: name.to_string(),
+            file: file.into(),
+            params: Params::default(),
+            part1: None,
+            part2: None,
+        }
+    }
+
+    /// The parts whose answer differs from the one listed here, with the expected answer.
+    pub fn mismatches(&self, part1: &str, part2: Option<&str>) -> Vec<(Phase, &str)> {
+        let mut mismatches = Vec::new();
+        if let Some(expected) = &self.part1 {
+            if expected != part1 {
+                mismatches.push((Phase::Part1, expected.as_str()));
+            }
+        }
+        if let Some(expected) = &self.part2 {
+            if Some(expected.as_str()) != part2 {
+                mismatches.push((Phase::Part2, expected.as_str()));
+            }
+        }
+        mismatches
+    }
+}
@@ -0,0 +1,172 @@
+//! Encryption of the puzzle inputs, so they don't have to be committed in plain text.
+//!
+//! An encrypted input is stored next to the plain one as `input.txt.enc` and consists of
+//! [`MAGIC`], a 16 byte salt, a 24 byte nonce and the XChaCha20-Poly1305 ciphertext. The key is
+//! derived with Argon2id from the passphrase in [`KEY_VAR`] and the salt, both the salt and the
+//! nonce are random for every encryption.
+
+use std::{
+    io,
+    path::{Path, PathBuf},
+};
+
+use argon2::Argon2;
+use chacha20poly1305::{
+    aead::{rand_core::RngCore, Aead, AeadCore, OsRng},
+    KeyInit, XChaCha20Poly1305, XNonce,
+};
+
+/// Environment variable holding the passphrase for the encrypted inputs.
+pub const KEY_VAR: &str = "AOC_INPUT_KEY";
+
+const MAGIC: &[u8; 4] = b"AOC2";
+const SALT_LEN: usize = 16;
+const NONCE_LEN: usize = 24;
+
+fn passphrase() -> io::Result<String> {
+    std::env::var(KEY_VAR).map_err(|_| {
+        io::Error::new(
+            io::ErrorKind::NotFound,
+            format!("{KEY_VAR} is not set, cannot use encrypted inputs"),
+        )
+    })
+}
+
+/// The cipher for the key derived from `passphrase` and `salt`, with the default Argon2id
+/// parameters (19 MiB of memory and two passes).
+fn cipher(passphrase: &str, salt: &[u8]) -> io::Result<XChaCha20Poly1305> {
+    let mut key = [0; 32];
+    Argon2::default()
+        .hash_password_into(passphrase.as_bytes(), salt, &mut key)
+        .map_err(|err| io::Error::other(format!("cannot derive the key: {err}")))?;
+    Ok(XChaCha20Poly1305::new(&key.into()))
+}
+
+/// Path of the encrypted counterpart of `path`, i.e. `input.txt` -> `input.txt.enc`.
+pub fn encrypted_path(path: &Path) -> PathBuf {
+    let mut path = path.as_os_str().to_owned();
+    path.push(".enc");
+    PathBuf::from(path)
+}
+
+pub fn is_encrypted_path(path: &Path) -> bool {
+    path.extension().is_some_and(|ext| ext == "enc")
+}
+
+/// Encrypts `plain` with the passphrase in [`KEY_VAR`].
+pub fn encrypt(plain: &[u8]) -> io::Result<Vec<u8>> {
+    encrypt_with(&passphrase()?, plain)
+}
+
+/// Decrypts an encrypted input with the passphrase in [`KEY_VAR`].
+pub fn decrypt(encrypted: &[u8]) -> io::Result<String> {
+    decrypt_with(&passphrase()?, encrypted)
+}
+
+fn encrypt_with(passphrase: &str, plain: &[u8]) -> io::Result<Vec<u8>> {
+    let mut salt = [0; SALT_LEN];
+    OsRng.fill_bytes(&mut salt);
+    let nonce = XChaCha20Poly1305::generate_nonce(&mut OsRng);
+    let ciphertext = cipher(passphrase, &salt)?
+        .encrypt(&nonce, plain)
+        .map_err(|_| io::Error::other("encryption failed"))?;
+
+    let mut encrypted = Vec::with_capacity(MAGIC.len() + SALT_LEN + NONCE_LEN + ciphertext.len());
+    encrypted.extend_from_slice(MAGIC);
+    encrypted.extend_from_slice(&salt);
+    encrypted.extend_from_slice(&nonce);
+    encrypted.extend_from_slice(&ciphertext);
+    Ok(encrypted)
+}
+
+fn decrypt_with(passphrase: &str, encrypted: &[u8]) -> io::Result<String> {
+    let invalid = |msg: &str| io::Error::new(io::ErrorKind::InvalidData, msg.to_string());
+
+    let Some(rest) = encrypted.strip_prefix(MAGIC) else {
+        return Err(invalid("not an encrypted input"));
+    };
+    if rest.len() < SALT_LEN + NONCE_LEN {
+        return Err(invalid("encrypted input is truncated"));
+    }
+    let (salt, rest) = rest.split_at(SALT_LEN);
+    let (nonce, ciphertext) = rest.split_at(NONCE_LEN);
+
+    let plain = cipher(passphrase, salt)?
+        .decrypt(XNonce::from_slice(nonce), ciphertext)
+        .map_err(|_| invalid("cannot decrypt input, wrong key?"))?;
+
+    String::from_utf8(plain).map_err(|_| invalid("decrypted input is not UTF-8"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const KEY: &str = "correct horse battery staple";
+    const INPUT: &str = "3   4\n4   3\n2   5\n";
+
+    fn assert_invalid(result: io::Result<String>, message: &str) {
+        let err = result.unwrap_err();
+        assert_eq!(err.kind(), io::ErrorKind::InvalidData);
+        assert_eq!(err.to_string(), message);
+    }
+
+    #[test]
+    fn round_trip() {
+        let encrypted = encrypt_with(KEY, INPUT.as_bytes()).unwrap();
+        assert!(encrypted.starts_with(MAGIC));
+        assert!(!encrypted
+            .windows(INPUT.len())
+            .any(|window| window == INPUT.as_bytes()));
+        assert_eq!(decrypt_with(KEY, &encrypted).unwrap(), INPUT);
+    }
+
+    #[test]
+    fn salt_and_nonce_are_random() {
+        let first = encrypt_with(KEY, INPUT.as_bytes()).unwrap();
+        let second = encrypt_with(KEY, INPUT.as_bytes()).unwrap();
+        assert_ne!(first, second);
+    }
+
+    #[test]
+    fn wrong_key() {
+        let encrypted = encrypt_with(KEY, INPUT.as_bytes()).unwrap();
+        assert_invalid(
+            decrypt_with("wrong key", &encrypted),
+            "cannot decrypt input, wrong key?",
+        );
+    }
+
+    #[test]
+    fn truncated() {
+        let encrypted = encrypt_with(KEY, INPUT.as_bytes()).unwrap();
+        assert_invalid(
+            decrypt_with(KEY, &encrypted[..MAGIC.len() + SALT_LEN]),
+            "encrypted input is truncated",
+        );
+        // the authentication tag doesn't match the shortened ciphertext
+        assert_invalid(
+            decrypt_with(KEY, &encrypted[..encrypted.len() - 1]),
+            "cannot decrypt input, wrong key?",
+        );
+    }
+
+    #[test]
+    fn bad_magic() {
+        let mut encrypted = encrypt_with(KEY, INPUT.as_bytes()).unwrap();
+        encrypted[..MAGIC.len()].copy_from_slice(b"AOC1");
+        assert_invalid(decrypt_with(KEY, &encrypted), "not an encrypted input");
+        assert_invalid(
+            decrypt_with(KEY, INPUT.as_bytes()),
+            "not an encrypted input",
+        );
+    }
+
+    #[test]
+    fn encrypted_paths() {
+        let path = encrypted_path(Path::new("d01/input.txt"));
+        assert_eq!(path, Path::new("d01/input.txt.enc"));
+        assert!(is_encrypted_path(&path));
+        assert!(!is_encrypted_path(Path::new("d01/input.txt")));
+    }
+}
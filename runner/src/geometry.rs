@@ -0,0 +1,310 @@
+//! Positions and directions on the character grids most days parse their input into.
+//!
+//! Rows grow downwards and columns to the right, like the lines and characters of the input. With
+//! the `nalgebra` feature a [`Pos`] converts to and from a `Vector2`, with `x` as the column and
+//! `y` as the row.
+
+use std::{
+    fmt::Display,
+    ops::{Add, AddAssign, Mul, Neg, Sub, SubAssign},
+};
+
+/// A position on a grid or the offset between two positions.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Pos {
+    pub row: isize,
+    pub col: isize,
+}
+
+impl Pos {
+    pub const ZERO: Self = Self::new(0, 0);
+
+    pub const fn new(row: isize, col: isize) -> Self {
+        Self { row, col }
+    }
+
+    /// The position of `grid[row][col]`.
+    pub fn from_indices(row: usize, col: usize) -> Self {
+        Self::new(row as isize, col as isize)
+    }
+
+    /// The row and column index, `None` above or left of the grid.
+    pub fn indices(self) -> Option<(usize, usize)> {
+        Some((self.row.try_into().ok()?, self.col.try_into().ok()?))
+    }
+
+    pub fn step(self, dir: impl Into<Dir8>) -> Self {
+        self + dir.into().offset()
+    }
+
+    pub fn manhattan(self, other: Self) -> usize {
+        self.row.abs_diff(other.row) + self.col.abs_diff(other.col)
+    }
+
+    /// Rotates the offset by 90° clockwise around the origin.
+    pub fn rotate_right(self) -> Self {
+        Self::new(self.col, -self.row)
+    }
+
+    /// Rotates the offset by 90° counterclockwise around the origin.
+    pub fn rotate_left(self) -> Self {
+        Self::new(-self.col, self.row)
+    }
+
+    pub fn in_bounds(self, num_rows: usize, num_cols: usize) -> bool {
+        self.indices()
+            .is_some_and(|(row, col)| row < num_rows && col < num_cols)
+    }
+
+    /// Wraps the position around the edges of a grid with the given size, like a torus.
+    pub fn wrap(self, num_rows: usize, num_cols: usize) -> Self {
+        Self::new(
+            self.row.rem_euclid(num_rows as isize),
+            self.col.rem_euclid(num_cols as isize),
+        )
+    }
+
+    /// The cell of `grid` at this position, `None` outside of it.
+    pub fn get<T>(self, grid: &[Vec<T>]) -> Option<&T> {
+        let (row, col) = self.indices()?;
+        grid.get(row)?.get(col)
+    }
+
+    /// The four positions next to this one, clockwise starting above it.
+    pub fn neighbors4(self) -> [Self; 4] {
+        Dir::ALL.map(|dir| self.step(dir))
+    }
+
+    /// The eight positions around this one, clockwise starting above it.
+    pub fn neighbors8(self) -> [Self; 8] {
+        Dir8::ALL.map(|dir| self.step(dir))
+    }
+}
+
+impl Display for Pos {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "[{}, {}]", self.row, self.col)
+    }
+}
+
+impl Add for Pos {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        Self::new(self.row + rhs.row, self.col + rhs.col)
+    }
+}
+
+impl AddAssign for Pos {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = *self + rhs;
+    }
+}
+
+impl Sub for Pos {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        Self::new(self.row - rhs.row, self.col - rhs.col)
+    }
+}
+
+impl SubAssign for Pos {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = *self - rhs;
+    }
+}
+
+impl Neg for Pos {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self::new(-self.row, -self.col)
+    }
+}
+
+impl Mul<isize> for Pos {
+    type Output = Self;
+
+    fn mul(self, rhs: isize) -> Self {
+        Self::new(self.row * rhs, self.col * rhs)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<nalgebra::Vector2<isize>> for Pos {
+    fn from(vector: nalgebra::Vector2<isize>) -> Self {
+        Self::new(vector.y, vector.x)
+    }
+}
+
+#[cfg(feature = "nalgebra")]
+impl From<Pos> for nalgebra::Vector2<isize> {
+    fn from(pos: Pos) -> Self {
+        nalgebra::Vector2::new(pos.col, pos.row)
+    }
+}
+
+/// One of the four directions along the axes of the grid.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir {
+    /// All directions, clockwise starting with [`Dir::Up`].
+    pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+    pub fn offset(self) -> Pos {
+        match self {
+            Self::Up => Pos::new(-1, 0),
+            Self::Right => Pos::new(0, 1),
+            Self::Down => Pos::new(1, 0),
+            Self::Left => Pos::new(0, -1),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        match self {
+            Self::Up => Self::Right,
+            Self::Right => Self::Down,
+            Self::Down => Self::Left,
+            Self::Left => Self::Up,
+        }
+    }
+
+    pub fn turn_left(self) -> Self {
+        self.turn_right().reverse()
+    }
+
+    pub fn reverse(self) -> Self {
+        self.turn_right().turn_right()
+    }
+
+    pub fn axis(self) -> Axis {
+        match self {
+            Self::Up | Self::Down => Axis::Vertical,
+            Self::Left | Self::Right => Axis::Horizontal,
+        }
+    }
+
+    /// Moves the row and column index one step, for grids surrounded by a border that is never
+    /// crossed.
+    ///
+    /// # Panics
+    /// If the step leaves the grid to the top or left.
+    pub fn apply(self, row: usize, col: usize) -> (usize, usize) {
+        Pos::from_indices(row, col)
+            .step(self)
+            .indices()
+            .unwrap_or_else(|| panic!("stepped {self:?} off the grid at [{row}, {col}]"))
+    }
+
+    /// The arrow of the direction in the puzzles, `^`, `>`, `v` or `<`.
+    pub fn arrow(self) -> char {
+        match self {
+            Self::Up => '^',
+            Self::Right => '>',
+            Self::Down => 'v',
+            Self::Left => '<',
+        }
+    }
+
+    pub fn from_arrow(arrow: char) -> Option<Self> {
+        Self::ALL.into_iter().find(|dir| dir.arrow() == arrow)
+    }
+}
+
+impl Display for Dir {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.arrow())
+    }
+}
+
+/// One of the eight directions to the neighbors of a cell, including the diagonals.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Dir8 {
+    Up,
+    UpRight,
+    Right,
+    DownRight,
+    Down,
+    DownLeft,
+    Left,
+    UpLeft,
+}
+
+impl Dir8 {
+    /// All directions, clockwise starting with [`Dir8::Up`].
+    pub const ALL: [Self; 8] = [
+        Self::Up,
+        Self::UpRight,
+        Self::Right,
+        Self::DownRight,
+        Self::Down,
+        Self::DownLeft,
+        Self::Left,
+        Self::UpLeft,
+    ];
+
+    pub fn offset(self) -> Pos {
+        match self {
+            Self::Up => Pos::new(-1, 0),
+            Self::UpRight => Pos::new(-1, 1),
+            Self::Right => Pos::new(0, 1),
+            Self::DownRight => Pos::new(1, 1),
+            Self::Down => Pos::new(1, 0),
+            Self::DownLeft => Pos::new(1, -1),
+            Self::Left => Pos::new(0, -1),
+            Self::UpLeft => Pos::new(-1, -1),
+        }
+    }
+
+    /// Turns by 45° clockwise.
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 8]
+    }
+
+    /// Turns by 45° counterclockwise.
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 7) % 8]
+    }
+
+    pub fn reverse(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+
+    pub fn is_diagonal(self) -> bool {
+        self as usize % 2 == 1
+    }
+}
+
+impl From<Dir> for Dir8 {
+    fn from(dir: Dir) -> Self {
+        match dir {
+            Dir::Up => Self::Up,
+            Dir::Right => Self::Right,
+            Dir::Down => Self::Down,
+            Dir::Left => Self::Left,
+        }
+    }
+}
+
+/// The orientation of a line on the grid, like a side of a fence on day 12.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Axis {
+    Vertical,
+    Horizontal,
+}
+
+impl Axis {
+    pub fn perpendicular(self) -> Self {
+        match self {
+            Self::Vertical => Self::Horizontal,
+            Self::Horizontal => Self::Vertical,
+        }
+    }
+}
@@ -0,0 +1,303 @@
+use std::{
+    fmt::Display,
+    io::{self, Read},
+    path::Path,
+    time::{Duration, Instant},
+};
+
+mod alloc;
+pub mod cache;
+pub mod config;
+pub mod crypt;
+pub mod geometry;
+pub mod overflow;
+pub mod par;
+pub mod parse;
+pub mod text;
+
+pub use alloc::{AllocStats, CountingAlloc};
+pub use config::Params;
+pub use parse::ParseError;
+
+use config::{DayConfig, InputConfig};
+
+#[global_allocator]
+static ALLOC: CountingAlloc = CountingAlloc;
+
+/// A single day of the calendar, split into the phases the runner measures.
+///
+/// The runner hands the input to `parse` normalized by [`text::normalize`].
+pub trait Solution {
+    const DAY: u8;
+
+    type Input;
+
+    /// Returns an error instead of panicking for inputs that don't look like the puzzle's.
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+
+    /// Parses an input together with its parameters from `aoc.toml`, like the smaller grid of
+    /// an example. Days without parameters don't need to implement this.
+    fn parse_with(input: &str, params: &Params) -> Result<Self::Input, ParseError> {
+        let _ = params;
+        Self::parse(input)
+    }
+
+    fn part1(input: &Self::Input) -> String;
+
+    /// `None` for days without a second puzzle (day 25).
+    fn part2(input: &Self::Input) -> Option<String>;
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Phase {
+    Parse,
+    Part1,
+    Part2,
+}
+
+impl Display for Phase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Phase::Parse => write!(f, "Parsing"),
+            Phase::Part1 => write!(f, "Task 1"),
+            Phase::Part2 => write!(f, "Task 2"),
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug)]
+pub struct PhaseReport {
+    pub phase: Phase,
+    pub elapsed: Duration,
+    pub alloc: AllocStats,
+    /// The result came from the [`cache`], nothing was measured.
+    pub cached: bool,
+}
+
+impl PhaseReport {
+    pub fn cached(phase: Phase) -> Self {
+        Self {
+            phase,
+            elapsed: Duration::ZERO,
+            alloc: AllocStats::default(),
+            cached: true,
+        }
+    }
+}
+
+#[derive(Clone, Debug)]
+pub struct Report {
+    pub day: u8,
+    pub part1: String,
+    pub part2: Option<String>,
+    pub phases: [PhaseReport; 3],
+}
+
+impl Report {
+    pub fn total_time(&self) -> Duration {
+        self.phases.iter().map(|phase| phase.elapsed).sum()
+    }
+
+    /// The parts whose answer differs from the one `expected` lists, with the expected answer.
+    pub fn mismatches<'a>(&self, expected: &'a InputConfig) -> Vec<(Phase, &'a str)> {
+        expected.mismatches(&self.part1, self.part2.as_deref())
+    }
+}
+
+/// Runs `f` and records how long it took and what it allocated on the current thread.
+pub fn measure<T>(phase: Phase, f: impl FnOnce() -> T) -> (T, PhaseReport) {
+    let scope = alloc::AllocScope::start();
+    let start = Instant::now();
+    let result = f();
+    let elapsed = start.elapsed();
+    let alloc = scope.finish();
+
+    (
+        result,
+        PhaseReport {
+            phase,
+            elapsed,
+            alloc,
+            cached: false,
+        },
+    )
+}
+
+pub fn run<S: Solution>(input: &str, params: &Params) -> Result<Report, ParseError> {
+    let input = &text::normalize(input);
+    let (parsed, parse) = measure(Phase::Parse, || S::parse_with(input, params));
+    let parsed = parsed?;
+    let (part1, part1_report) = measure(Phase::Part1, || S::part1(&parsed));
+    let (part2, part2_report) = measure(Phase::Part2, || S::part2(&parsed));
+
+    Ok(Report {
+        day: S::DAY,
+        part1,
+        part2,
+        phases: [parse, part1_report, part2_report],
+    })
+}
+
+/// Solves only one part of the puzzle, e.g. for inputs where the other part has no answer.
+pub fn solve_part<S: Solution>(
+    input: &str,
+    params: &Params,
+    phase: Phase,
+) -> Result<Option<String>, ParseError> {
+    let input = &text::normalize(input);
+    let parsed = S::parse_with(input, params)?;
+    Ok(match phase {
+        Phase::Parse => None,
+        Phase::Part1 => Some(S::part1(&parsed)),
+        Phase::Part2 => S::part2(&parsed),
+    })
+}
+
+/// Reads the puzzle input from `path`, `-` reads it from stdin instead.
+///
+/// Encrypted inputs are decrypted transparently, either when `path` names an `.enc` file or when
+/// `path` itself is missing but its encrypted counterpart exists (see [`crypt`]).
+pub fn read_input(path: &Path) -> io::Result<String> {
+    if path == Path::new("-") {
+        let mut input = String::new();
+        io::stdin().read_to_string(&mut input)?;
+        return Ok(input);
+    }
+
+    if crypt::is_encrypted_path(path) {
+        return crypt::decrypt(&std::fs::read(path)?);
+    }
+
+    match std::fs::read_to_string(path) {
+        Err(err) if err.kind() == io::ErrorKind::NotFound => {
+            let encrypted_path = crypt::encrypted_path(path);
+            if encrypted_path.exists() {
+                crypt::decrypt(&std::fs::read(encrypted_path)?)
+            } else {
+                Err(err)
+            }
+        }
+        result => result,
+    }
+}
+
+/// Prints `message` to stderr and exits the process with an error, for the per-day binaries.
+pub fn exit_with(message: impl Display) -> ! {
+    eprintln!("{message}");
+    std::process::exit(1);
+}
+
+/// Looks up the input named `selection` in the `aoc.toml` of `day_dir` (or the path `selection`,
+/// `-` for stdin) without reading it, for binaries that read the input themselves. Exits the
+/// process if the configuration cannot be read.
+pub fn load_config(day_dir: impl AsRef<Path>, selection: &str) -> InputConfig {
+    let config = DayConfig::load(day_dir.as_ref())
+        .unwrap_or_else(|err| exit_with(format!("Cannot load {}: {err}", DayConfig::FILE_NAME)));
+    config.select(selection)
+}
+
+/// Like [`load_config`], and reads the input too. Exits the process if the input cannot be read.
+pub fn load_selection(day_dir: impl AsRef<Path>, selection: &str) -> (InputConfig, String) {
+    let selected = load_config(day_dir, selection);
+    let input = read_input(&selected.file).unwrap_or_else(|err| {
+        exit_with(format!(
+            "Cannot read input {}: {err}",
+            selected.file.display()
+        ))
+    });
+    (selected, input)
+}
+
+/// Prints the parts whose answer differs from the one `expected` lists and exits the process with
+/// an error if there are any.
+pub fn check_answers(expected: &InputConfig, part1: &str, part2: Option<&str>) {
+    let mismatches = expected.mismatches(part1, part2);
+    for (phase, expected) in &mismatches {
+        eprintln!("{phase} is wrong, expected {expected}");
+    }
+    if !mismatches.is_empty() {
+        std::process::exit(1);
+    }
+}
+
+/// Entry point for the per-day binaries: solves the input selected by the first command line
+/// argument and prints the report. The argument is the name of an input in the `aoc.toml` of
+/// `day_dir` (`input` by default), a path, or `-` for stdin. Exits with an error if an answer
+/// differs from the expected one or for flags, which these binaries have none of.
+pub fn run_main<S: Solution>(day_dir: impl AsRef<Path>) {
+    let mut selection = "input".to_string();
+    for arg in std::env::args().skip(1) {
+        if arg.starts_with("--") {
+            exit_with(format!("usage: day{} [INPUT]", S::DAY));
+        }
+        selection = arg;
+    }
+    run_selection::<S>(day_dir, &selection);
+}
+
+/// Like [`run_main`], for binaries with command line flags of their own that pick `selection`
+/// from their arguments themselves.
+pub fn run_selection<S: Solution>(day_dir: impl AsRef<Path>, selection: &str) {
+    let (selected, input) = load_selection(day_dir, selection);
+    let report = run::<S>(&input, &selected.params).unwrap_or_else(|err| {
+        exit_with(format!(
+            "Cannot parse input {}: {err}",
+            selected.file.display()
+        ))
+    });
+    print!("{report}");
+    check_answers(&selected, &report.part1, report.part2.as_deref());
+}
+
+pub fn format_duration(duration: Duration) -> String {
+    if duration.as_millis() >= 10 {
+        format!("{}ms", duration.as_millis())
+    } else {
+        format!("{}µs", duration.as_micros())
+    }
+}
+
+pub fn format_bytes(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["B", "KiB", "MiB", "GiB"];
+
+    let mut value = bytes as f64;
+    let mut unit = 0;
+    while value >= 1024.0 && unit + 1 < UNITS.len() {
+        value /= 1024.0;
+        unit += 1;
+    }
+
+    if unit == 0 {
+        format!("{bytes}B")
+    } else {
+        format!("{value:.1}{}", UNITS[unit])
+    }
+}
+
+impl Display for Report {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "Task1: {}", self.part1)?;
+        if let Some(part2) = &self.part2 {
+            writeln!(f, "Task2: {part2}")?;
+        }
+
+        for phase in &self.phases {
+            if phase.cached {
+                writeln!(f, "{} cached", phase.phase)?;
+                continue;
+            }
+
+            writeln!(
+                f,
+                "{} took: {:>8}, {:>8} allocs, {:>9} allocated, {:>9} peak",
+                phase.phase,
+                format_duration(phase.elapsed),
+                phase.alloc.allocations,
+                format_bytes(phase.alloc.bytes),
+                format_bytes(phase.alloc.peak),
+            )?;
+        }
+
+        Ok(())
+    }
+}
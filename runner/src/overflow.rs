@@ -0,0 +1,89 @@
+//! Arithmetic for the places where a larger input could exceed the integer types the solvers
+//! assume, like the `stone * 2024` of day 11.
+//!
+//! Normally these are the plain operations. With the `checked` feature every operation is checked
+//! and an overflow panics with the day and the operation, so `aoc all` reports which input broke
+//! which assumption instead of printing a wrong answer.
+
+use std::fmt::Display;
+
+pub trait Int: Copy + Display {
+    fn checked_add(self, rhs: Self) -> Option<Self>;
+    fn checked_mul(self, rhs: Self) -> Option<Self>;
+    fn unchecked_add(self, rhs: Self) -> Self;
+    fn unchecked_mul(self, rhs: Self) -> Self;
+    fn wrapping_mul(self, rhs: Self) -> Self;
+}
+
+macro_rules! impl_int {
+    ($($t:ty),*) => {
+        $(impl Int for $t {
+            fn checked_add(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_add(self, rhs)
+            }
+
+            fn checked_mul(self, rhs: Self) -> Option<Self> {
+                <$t>::checked_mul(self, rhs)
+            }
+
+            fn unchecked_add(self, rhs: Self) -> Self {
+                self + rhs
+            }
+
+            fn unchecked_mul(self, rhs: Self) -> Self {
+                self * rhs
+            }
+
+            fn wrapping_mul(self, rhs: Self) -> Self {
+                <$t>::wrapping_mul(self, rhs)
+            }
+        })*
+    };
+}
+
+impl_int!(u32, u64, u128, usize, i32, i64, i128, isize);
+
+#[cold]
+#[track_caller]
+fn overflowed<T: Int>(day: u8, op: &str, lhs: T, symbol: char, rhs: T) -> ! {
+    panic!("day {day} overflowed in `{op}` ({lhs} {symbol} {rhs})")
+}
+
+/// `lhs + rhs`, where `op` describes the operation for the overflow report.
+#[inline]
+#[track_caller]
+pub fn add<T: Int>(day: u8, op: &str, lhs: T, rhs: T) -> T {
+    if cfg!(feature = "checked") {
+        match lhs.checked_add(rhs) {
+            Some(result) => result,
+            None => overflowed(day, op, lhs, '+', rhs),
+        }
+    } else {
+        lhs.unchecked_add(rhs)
+    }
+}
+
+/// `lhs * rhs`, where `op` describes the operation for the overflow report.
+#[inline]
+#[track_caller]
+pub fn mul<T: Int>(day: u8, op: &str, lhs: T, rhs: T) -> T {
+    if cfg!(feature = "checked") {
+        match lhs.checked_mul(rhs) {
+            Some(result) => result,
+            None => overflowed(day, op, lhs, '*', rhs),
+        }
+    } else {
+        lhs.unchecked_mul(rhs)
+    }
+}
+
+/// Like [`mul`], but wraps around instead of panicking in debug builds without `checked`.
+#[inline]
+#[track_caller]
+pub fn wrapping_mul<T: Int>(day: u8, op: &str, lhs: T, rhs: T) -> T {
+    if cfg!(feature = "checked") {
+        mul(day, op, lhs, rhs)
+    } else {
+        lhs.wrapping_mul(rhs)
+    }
+}
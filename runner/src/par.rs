@@ -0,0 +1,64 @@
+//! Loops over independent items, like the reports of day 2 or the cheat start cells of day 20.
+//!
+//! Normally these are plain iterators. With the `parallel` feature the items are processed on the
+//! rayon thread pool, which splits the work between the threads by work stealing. The results are
+//! combined in the order of the items, so as long as `reduce` is associative the answers are the
+//! same with and without the feature. With the feature the allocation counters are shared by all
+//! threads, so a phase also counts what the worker threads allocate for it, but also everything
+//! else the process allocates in the meantime. So the phases are only measured correctly if
+//! nothing else runs at the same time, which is why `aoc all` solves the days one after the other
+//! with this feature.
+
+#[cfg(feature = "parallel")]
+use rayon::iter::{IntoParallelIterator, ParallelIterator};
+
+/// Something to loop over, like a slice or a range. Both variants require the items to be `Send`,
+/// so a day that builds without the `parallel` feature also builds with it.
+#[cfg(not(feature = "parallel"))]
+pub trait Items: IntoIterator<Item: Send> {}
+
+#[cfg(not(feature = "parallel"))]
+impl<I: IntoIterator<Item: Send>> Items for I {}
+
+/// Something to loop over, like a slice or a range.
+#[cfg(feature = "parallel")]
+pub trait Items: IntoParallelIterator {}
+
+#[cfg(feature = "parallel")]
+impl<I: IntoParallelIterator> Items for I {}
+
+/// Maps every item with `map` and combines the results with `reduce`, starting from `identity`.
+pub fn map_reduce<I, T, M, R>(items: I, identity: T, map: M, reduce: R) -> T
+where
+    I: Items,
+    T: Clone + Send + Sync,
+    M: Fn(I::Item) -> T + Sync + Send,
+    R: Fn(T, T) -> T + Sync + Send,
+{
+    #[cfg(not(feature = "parallel"))]
+    return items.into_iter().map(map).fold(identity, reduce);
+
+    #[cfg(feature = "parallel")]
+    return items
+        .into_par_iter()
+        .map(map)
+        .reduce(|| identity.clone(), reduce);
+}
+
+/// Sums `f` over all items.
+pub fn sum<I, F>(items: I, f: F) -> u64
+where
+    I: Items,
+    F: Fn(I::Item) -> u64 + Sync + Send,
+{
+    map_reduce(items, 0, f, |lhs, rhs| lhs + rhs)
+}
+
+/// Counts the items that fulfill `pred`.
+pub fn count<I, P>(items: I, pred: P) -> u64
+where
+    I: Items,
+    P: Fn(I::Item) -> bool + Sync + Send,
+{
+    sum(items, |item| pred(item) as u64)
+}
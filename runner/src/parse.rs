@@ -0,0 +1,115 @@
+//! The error of [`Solution::parse`](crate::Solution::parse) and helpers for the pieces most
+//! inputs are made of, numbers and character grids.
+
+use std::{error::Error, fmt::Display, str::FromStr};
+
+/// Why an input doesn't look like the puzzle describes it, optionally with the line.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ParseError {
+    line_idx: Option<usize>,
+    message: String,
+}
+
+impl ParseError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self {
+            line_idx: None,
+            message: message.into(),
+        }
+    }
+
+    pub fn unexpected_char(c: char) -> Self {
+        Self::new(format!("unexpected character {c:?}"))
+    }
+
+    /// Attaches the index of the line the error was found in, unless it already has one.
+    pub fn in_line(mut self, line_idx: usize) -> Self {
+        self.line_idx.get_or_insert(line_idx);
+        self
+    }
+}
+
+impl Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.line_idx {
+            Some(line_idx) => write!(f, "line {}: {}", line_idx + 1, self.message),
+            None => write!(f, "{}", self.message),
+        }
+    }
+}
+
+impl Error for ParseError {}
+
+/// `str::parse` with an error that names the text.
+pub fn number<T: FromStr>(text: &str) -> Result<T, ParseError> {
+    text.parse()
+        .map_err(|_| ParseError::new(format!("invalid number {text:?}")))
+}
+
+/// Parses every line of `input` with `line`, the errors get the line attached.
+pub fn lines<'a, T>(
+    input: &'a str,
+    mut line: impl FnMut(&'a str) -> Result<T, ParseError>,
+) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .enumerate()
+        .map(|(line_idx, text)| line(text).map_err(|err| err.in_line(line_idx)))
+        .collect()
+}
+
+/// Parses a rectangular, non-empty grid and converts every character with `cell`, which gets the
+/// row and column of the character as well.
+pub fn grid_with<T>(
+    input: &str,
+    mut cell: impl FnMut(usize, usize, char) -> Result<T, ParseError>,
+) -> Result<Vec<Vec<T>>, ParseError> {
+    let mut num_cols = None;
+    let mut grid = Vec::new();
+    for (row_idx, line) in input.lines().enumerate() {
+        let row = line
+            .chars()
+            .enumerate()
+            .map(|(col_idx, c)| cell(row_idx, col_idx, c))
+            .collect::<Result<Vec<T>, _>>()
+            .map_err(|err| err.in_line(row_idx))?;
+
+        if row.is_empty() {
+            return Err(ParseError::new("empty row").in_line(row_idx));
+        }
+        let expected_cols = *num_cols.get_or_insert(row.len());
+        if row.len() != expected_cols {
+            let message = format!("expected {expected_cols} columns, found {}", row.len());
+            return Err(ParseError::new(message).in_line(row_idx));
+        }
+        grid.push(row);
+    }
+
+    if grid.is_empty() {
+        return Err(ParseError::new("empty grid"));
+    }
+    Ok(grid)
+}
+
+/// Parses a rectangular, non-empty grid of characters.
+pub fn grid(input: &str) -> Result<Vec<Vec<char>>, ParseError> {
+    grid_with(input, |_, _, c| Ok(c))
+}
+
+/// Checks that the border of `grid` only consists of walls, so walking around on it can't leave
+/// the grid.
+pub fn walled<T>(grid: &[Vec<T>], is_wall: impl Fn(&T) -> bool) -> Result<(), ParseError> {
+    let last_row = grid.len().saturating_sub(1);
+    for (row_idx, row) in grid.iter().enumerate() {
+        let last_col = row.len().saturating_sub(1);
+        let is_walled = row.iter().enumerate().all(|(col_idx, cell)| {
+            let on_border =
+                row_idx == 0 || row_idx == last_row || col_idx == 0 || col_idx == last_col;
+            !on_border || is_wall(cell)
+        });
+        if !is_walled {
+            return Err(ParseError::new("the grid is not surrounded by walls").in_line(row_idx));
+        }
+    }
+    Ok(())
+}
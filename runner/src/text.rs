@@ -0,0 +1,114 @@
+//! Helpers for inputs that were not saved exactly like the website serves them, e.g. with
+//! Windows line endings or an editor that adds trailing whitespace.
+
+use std::borrow::Cow;
+
+/// Normalizes the line endings to `\n`, removes trailing whitespace from every line and trailing
+/// empty lines, so every line of the result (including the last one) ends with a single `\n`.
+/// Inputs without any content become empty. Inputs that already look like that are returned
+/// unchanged without copying.
+pub fn normalize(input: &str) -> Cow<'_, str> {
+    let content = input.trim_end();
+    let is_normalized = !content.is_empty()
+        && input.len() == content.len() + 1
+        && input.ends_with('\n')
+        && content
+            .split('\n')
+            .all(|line| line.len() == line.trim_end().len());
+
+    if is_normalized || input.is_empty() {
+        return Cow::Borrowed(input);
+    }
+
+    let mut normalized = String::with_capacity(input.len());
+    for line in content.lines() {
+        normalized.push_str(line.trim_end());
+        normalized.push('\n');
+    }
+    Cow::Owned(normalized)
+}
+
+/// Splits `input` into its sections separated by blank lines, like the rules and the updates of
+/// day 5. Accepts `\r\n` line endings and whitespace on the separating lines, the sections don't
+/// include the line break of their last line.
+pub fn sections(input: &str) -> Vec<&str> {
+    let mut sections = Vec::new();
+    let mut section_start = None;
+    let mut section_end = 0;
+    let mut line_start = 0;
+
+    for line in input.split_inclusive('\n') {
+        if line.trim().is_empty() {
+            if let Some(start) = section_start.take() {
+                sections.push(&input[start..section_end]);
+            }
+        } else {
+            section_start.get_or_insert(line_start);
+            section_end = line_start + line.trim_end().len();
+        }
+        line_start += line.len();
+    }
+
+    if let Some(start) = section_start {
+        sections.push(&input[start..section_end]);
+    }
+
+    sections
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn normalize_keeps_normalized_inputs() {
+        assert!(matches!(
+            normalize("1 2\n3 4\n"),
+            Cow::Borrowed("1 2\n3 4\n")
+        ));
+        assert!(matches!(normalize(""), Cow::Borrowed("")));
+    }
+
+    #[test]
+    fn normalize_converts_crlf() {
+        assert_eq!(normalize("1 2\r\n3 4\r\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\r\n3 4"), "1 2\n3 4\n");
+    }
+
+    #[test]
+    fn normalize_removes_trailing_spaces() {
+        assert_eq!(normalize("1 2 \n3 4\t\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2  \r\n3 4  "), "1 2\n3 4\n");
+    }
+
+    #[test]
+    fn normalize_removes_trailing_blank_lines() {
+        assert_eq!(normalize("1 2\n3 4\n\n\n"), "1 2\n3 4\n");
+        assert_eq!(normalize("1 2\r\n\r\n  \r\n"), "1 2\n");
+    }
+
+    #[test]
+    fn normalize_empties_blank_inputs() {
+        for input in ["\n", " \n", "\r\n", "\n\n", "  "] {
+            assert_eq!(normalize(input), "", "{input:?}");
+        }
+    }
+
+    #[test]
+    fn normalize_empties_whitespace_only_separators() {
+        assert_eq!(normalize("a\n  \nb\n"), "a\n\nb\n");
+        assert_eq!(normalize("a\r\n\t\r\nb\r\n"), "a\n\nb\n");
+    }
+
+    #[test]
+    fn sections_split_on_blank_lines() {
+        assert_eq!(sections("a\nb\n\nc\n"), ["a\nb", "c"]);
+        assert_eq!(sections("a\n\n\n\nc"), ["a", "c"]);
+    }
+
+    #[test]
+    fn sections_accept_crlf_and_whitespace_separators() {
+        assert_eq!(sections("a\r\nb\r\n \r\nc\r\n"), ["a\r\nb", "c"]);
+        assert_eq!(sections("a  \n\t\nc  \n\n"), ["a", "c"]);
+    }
+}
@@ -0,0 +1,52 @@
+use std::{
+    hint::black_box,
+    sync::{Mutex, MutexGuard},
+};
+
+use runner::{measure, Phase};
+
+/// With the `parallel` feature the counters are shared by all threads, so the tests must not
+/// allocate at the same time.
+fn serialize() -> MutexGuard<'static, ()> {
+    static LOCK: Mutex<()> = Mutex::new(());
+    LOCK.lock().unwrap_or_else(|err| err.into_inner())
+}
+
+#[test]
+fn nested_scope_keeps_the_outer_peak() {
+    let _serialized = serialize();
+    let (inner, outer) = measure(Phase::Part1, || {
+        drop(black_box(vec![0u8; 1 << 20]));
+        let (_, inner) = measure(Phase::Part2, || drop(black_box(vec![0u8; 1 << 10])));
+        inner
+    });
+
+    assert!(inner.alloc.peak >= 1 << 10 && inner.alloc.peak < 1 << 20);
+    assert!(outer.alloc.peak >= 1 << 20);
+    assert_eq!(outer.alloc.allocations, 2);
+}
+
+#[test]
+fn nested_scope_counts_towards_the_outer_peak() {
+    let _serialized = serialize();
+    let (inner, outer) = measure(Phase::Part1, || {
+        let (_, inner) = measure(Phase::Part2, || drop(black_box(vec![0u8; 1 << 20])));
+        drop(black_box(vec![0u8; 1 << 10]));
+        inner
+    });
+
+    assert!(inner.alloc.peak >= 1 << 20);
+    assert!(outer.alloc.peak >= 1 << 20);
+}
+
+#[cfg(feature = "parallel")]
+#[test]
+fn counts_the_worker_threads() {
+    let _serialized = serialize();
+    let (_, report) = measure(Phase::Part1, || {
+        runner::par::sum(0..1000u64, |_| black_box(vec![0u8; 1 << 10]).len() as u64)
+    });
+
+    assert!(report.alloc.allocations >= 1000);
+    assert!(report.alloc.bytes >= 1000 << 10);
+}
@@ -0,0 +1,25 @@
+#![cfg(feature = "nalgebra")]
+
+use nalgebra::Vector2;
+use runner::geometry::{Dir, Pos};
+
+#[test]
+fn pos_round_trips_through_vector2() {
+    for row in -3..=3 {
+        for col in -3..=3 {
+            let pos = Pos::new(row, col);
+            let vector = Vector2::from(pos);
+            assert_eq!((vector.x, vector.y), (col, row));
+            assert_eq!(Pos::from(vector), pos);
+        }
+    }
+}
+
+#[test]
+fn offsets_add_like_vectors() {
+    let pos = Pos::new(4, 7);
+    for dir in Dir::ALL {
+        let moved = Vector2::from(pos) + Vector2::from(dir.offset());
+        assert_eq!(Pos::from(moved), pos + dir.offset());
+    }
+}
@@ -0,0 +1,54 @@
+use runner::overflow;
+
+#[test]
+fn in_range() {
+    assert_eq!(overflow::add(11, "stones + 1", 2u64, 3), 5);
+    assert_eq!(overflow::mul(11, "stone * 2024", 2u64, 2024), 4048);
+    assert_eq!(overflow::add(1, "distance", -2i64, 3), 1);
+    assert_eq!(overflow::wrapping_mul(22, "secret * 64", 3u32, 64), 192);
+}
+
+#[cfg(feature = "checked")]
+#[test]
+#[should_panic(expected = "day 11 overflowed in `stone * 2024` (18446744073709551615 * 2024)")]
+fn checked_mul_reports_the_day_and_operation() {
+    overflow::mul(11, "stone * 2024", u64::MAX, 2024);
+}
+
+#[cfg(feature = "checked")]
+#[test]
+#[should_panic(expected = "day 7 overflowed in `result + value` (4294967295 + 1)")]
+fn checked_add_reports_the_day_and_operation() {
+    overflow::add(7, "result + value", u32::MAX, 1);
+}
+
+#[cfg(feature = "checked")]
+#[test]
+#[should_panic(expected = "day 22 overflowed in `secret * 64` (4294967295 * 64)")]
+fn checked_wrapping_mul_reports_the_day_and_operation() {
+    overflow::wrapping_mul(22, "secret * 64", u32::MAX, 64);
+}
+
+#[cfg(not(feature = "checked"))]
+#[test]
+fn unchecked_wrapping_mul_wraps() {
+    assert_eq!(
+        overflow::wrapping_mul(22, "secret * 64", u32::MAX, 64),
+        u32::MAX.wrapping_mul(64)
+    );
+}
+
+// without `checked` the plain operations overflow like they always did, they panic in debug
+// builds and wrap in release builds
+#[cfg(not(feature = "checked"))]
+#[test]
+fn unchecked_add_and_mul_are_the_plain_operations() {
+    let add = std::panic::catch_unwind(|| overflow::add(7, "result + value", u32::MAX, 1));
+    let mul = std::panic::catch_unwind(|| overflow::mul(11, "stone * 2024", u64::MAX, 2024));
+    if cfg!(debug_assertions) {
+        assert!(add.is_err() && mul.is_err());
+    } else {
+        assert_eq!(add.unwrap(), u32::MAX.wrapping_add(1));
+        assert_eq!(mul.unwrap(), u64::MAX.wrapping_mul(2024));
+    }
+}